@@ -45,7 +45,10 @@ fn main() {
     let magic_offset = spec["offsets"]["magic"].as_u64().unwrap_or(0);
     let version_offset = spec["offsets"]["version"].as_u64().unwrap_or(8);
     let length_offset = spec["offsets"]["length"].as_u64().unwrap_or(16);
+    let checksum_offset = spec["offsets"]["checksum"].as_u64().unwrap_or(24);
+    let flags_offset = spec["offsets"]["flags"].as_u64().unwrap_or(28);
     let endianness = spec["endianness"].as_str().unwrap_or("little");
+    let has_checksum_flag = spec["flags"]["has_checksum"].as_u64().unwrap_or(1);
 
     // Generate Rust code
     let generated = format!(
@@ -67,6 +70,17 @@ pub const SHARED_STATE_VERSION_OFFSET: usize = {version_offset};
 /// Byte offset of the length field within the header
 pub const SHARED_STATE_LENGTH_OFFSET: usize = {length_offset};
 
+/// Byte offset of the CRC32C payload checksum within the header
+pub const SHARED_STATE_CHECKSUM_OFFSET: usize = {checksum_offset};
+
+/// Byte offset of the header flags word
+pub const SHARED_STATE_FLAGS_OFFSET: usize = {flags_offset};
+
+/// Flag bit set when the checksum field holds a valid CRC32C of the payload.
+/// Readers that predate this field ignore flags entirely, so old and new
+/// readers stay compatible.
+pub const SHARED_STATE_FLAG_HAS_CHECKSUM: u32 = {has_checksum_flag};
+
 /// Endianness of multi-byte fields
 pub const SHARED_STATE_ENDIANNESS: &str = "{endianness}";
 "#
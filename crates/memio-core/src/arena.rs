@@ -1,14 +1,81 @@
 //! Fixed-size memory arena.
+//!
+//! `alloc` is bump-only by default; [`Arena::dealloc`] reclaims a block back into
+//! a segregated free list keyed by its rounded-up size class *and* the alignment
+//! it was bump-allocated with, so long-lived shared regions don't have to be
+//! wiped wholesale via [`Arena::reset`] to recover space.
 
-use std::alloc::{Layout, alloc, dealloc};
+use std::alloc::{Layout, alloc, dealloc as dealloc_raw};
 use std::ptr::NonNull;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
-/// Pre-allocated memory block with bump allocation.
+/// Number of segregated size classes, each a power of two starting at
+/// `1 << MIN_SIZE_CLASS_SHIFT`. Allocations larger than the biggest class fall
+/// back to the bump path and are never reclaimed by `dealloc`.
+const NUM_SIZE_CLASSES: usize = 12;
+const MIN_SIZE_CLASS_SHIFT: u32 = 4; // smallest class is 16 bytes (room for a next-offset).
+
+/// Largest alignment the free list will reuse a block for. A block's offset is
+/// only guaranteed aligned to the alignment it was originally bump-allocated
+/// with, so free lists are also segregated by that alignment (see
+/// `align_class_for`); requests above this are always served by the bump path
+/// so a reused block can't silently under-align a caller.
+const FREE_LIST_MAX_ALIGN: usize = 8;
+
+/// Number of alignment buckets the free list segregates by: one per
+/// power-of-two alignment from 1 up to `FREE_LIST_MAX_ALIGN`.
+const NUM_ALIGN_CLASSES: usize = 4; // 1, 2, 4, 8
+
+/// Sentinel offset meaning "no block" in a free-list head or next-pointer.
+const NO_OFFSET: u32 = u32::MAX;
+
+/// Returns the size class index for `size`, or `None` if it exceeds the
+/// largest class (the bump path should be used instead).
+fn size_class_for(size: usize) -> Option<usize> {
+    let class_size = size.max(1).next_power_of_two().max(1 << MIN_SIZE_CLASS_SHIFT);
+    let shift = class_size.trailing_zeros();
+    let idx = shift.checked_sub(MIN_SIZE_CLASS_SHIFT)? as usize;
+    if idx < NUM_SIZE_CLASSES { Some(idx) } else { None }
+}
+
+/// Returns the block size in bytes for size class `idx`.
+fn class_size(idx: usize) -> usize {
+    1usize << (MIN_SIZE_CLASS_SHIFT + idx as u32)
+}
+
+/// Returns the alignment class index for `align`, or `None` if it's above
+/// `FREE_LIST_MAX_ALIGN` or not a power of two (the bump path should be used
+/// instead, same as an oversized `size`). A block only ever lands in the free
+/// list under the alignment class matching the exact `align` it was
+/// allocated with, so popping from a given class always hands back an offset
+/// aligned to at least that class's power of two.
+fn align_class_for(align: usize) -> Option<usize> {
+    let align = align.max(1);
+    if !align.is_power_of_two() || align > FREE_LIST_MAX_ALIGN {
+        return None;
+    }
+    Some(align.trailing_zeros() as usize)
+}
+
+fn pack_head(tag: u32, offset: u32) -> u64 {
+    ((tag as u64) << 32) | offset as u64
+}
+
+fn unpack_head(packed: u64) -> (u32, u32) {
+    ((packed >> 32) as u32, packed as u32)
+}
+
+/// Pre-allocated memory block with bump allocation and size-classed reuse.
 pub struct Arena {
     base: NonNull<u8>,
     capacity: usize,
     offset: AtomicUsize,
+    /// Treiber-stack free-list head per `(align class, size class)`: high 32
+    /// bits are an ABA generation tag, low 32 bits are the head block's
+    /// offset (`NO_OFFSET` if empty).
+    free_heads: [[AtomicU64; NUM_SIZE_CLASSES]; NUM_ALIGN_CLASSES],
+    /// Number of blocks currently parked in each free list, for `live()`.
+    free_counts: [[AtomicUsize; NUM_SIZE_CLASSES]; NUM_ALIGN_CLASSES],
 }
 
 unsafe impl Send for Arena {}
@@ -28,11 +95,75 @@ impl Arena {
             base,
             capacity,
             offset: AtomicUsize::new(0),
+            free_heads: std::array::from_fn(|_| {
+                std::array::from_fn(|_| AtomicU64::new(pack_head(0, NO_OFFSET)))
+            }),
+            free_counts: std::array::from_fn(|_| std::array::from_fn(|_| AtomicUsize::new(0))),
         }
     }
 
     /// Allocates `size` bytes with alignment. Returns None if full.
+    ///
+    /// If `size` fits a size class and `align` is small enough to reuse a
+    /// previously freed block (see [`Arena::dealloc`]), this first tries the
+    /// class's free list before falling back to the bump path.
     pub fn alloc(&self, size: usize, align: usize) -> Option<NonNull<u8>> {
+        if let (Some(aidx), Some(idx)) = (align_class_for(align), size_class_for(size)) {
+            if let Some(offset) = self.pop_free(aidx, idx) {
+                let ptr = unsafe { self.base.as_ptr().add(offset as usize) };
+                return NonNull::new(ptr);
+            }
+            // Free-list miss: bump-allocate a full class-sized block so a later
+            // `dealloc` of this size has somewhere to park it.
+            return self.bump_alloc(class_size(idx), align.max(1));
+        }
+
+        self.bump_alloc(size, align)
+    }
+
+    /// Returns a previously allocated block to its size class's free list.
+    ///
+    /// `size` and `align` must match the values passed to the `alloc` call that
+    /// produced `ptr`. Blocks whose size exceeds the largest size class, or
+    /// whose alignment exceeds [`FREE_LIST_MAX_ALIGN`], were served by the bump
+    /// path and cannot be reclaimed individually; `dealloc` is a no-op for them
+    /// (the arena stays bump-only for that block until [`Arena::reset`]).
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by a prior call to `alloc` on this arena
+    /// with the same `size`/`align`, must not already be freed, and must have
+    /// no outstanding references.
+    pub unsafe fn dealloc(&self, ptr: NonNull<u8>, size: usize, align: usize) {
+        let Some(aidx) = align_class_for(align) else {
+            return;
+        };
+        let Some(idx) = size_class_for(size) else {
+            return;
+        };
+
+        let byte_offset = unsafe { ptr.as_ptr().offset_from(self.base.as_ptr()) };
+        debug_assert!(byte_offset >= 0 && (byte_offset as usize) < self.capacity);
+        let offset = byte_offset as u32;
+
+        self.push_free(aidx, idx, offset);
+    }
+
+    /// Bytes allocated that are reclaimable via the free lists (not yet reused).
+    fn reclaimable(&self) -> usize {
+        self.free_counts
+            .iter()
+            .flat_map(|row| row.iter().enumerate())
+            .map(|(idx, count)| count.load(Ordering::Relaxed) * class_size(idx))
+            .sum()
+    }
+
+    /// Returns bytes genuinely in use: allocated minus what's sitting in free lists.
+    pub fn live(&self) -> usize {
+        self.used().saturating_sub(self.reclaimable())
+    }
+
+    fn bump_alloc(&self, size: usize, align: usize) -> Option<NonNull<u8>> {
+        let align = align.max(1);
         loop {
             let current = self.offset.load(Ordering::Relaxed);
             let aligned = (current + align - 1) & !(align - 1);
@@ -53,12 +184,90 @@ impl Arena {
         }
     }
 
+    /// Pops a block offset from alignment class `aidx`, size class `idx`'s
+    /// free list, if any.
+    fn pop_free(&self, aidx: usize, idx: usize) -> Option<u32> {
+        let head = &self.free_heads[aidx][idx];
+        loop {
+            let packed = head.load(Ordering::Acquire);
+            let (tag, offset) = unpack_head(packed);
+            if offset == NO_OFFSET {
+                return None;
+            }
+
+            // SAFETY: `offset` came from a prior `push_free`, which only stores
+            // offsets of blocks with a 4-byte next-offset word written at their start.
+            let next = unsafe { self.read_next(offset) };
+
+            let new_packed = pack_head(tag.wrapping_add(1), next);
+            if head
+                .compare_exchange_weak(packed, new_packed, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                self.free_counts[aidx][idx].fetch_sub(1, Ordering::Relaxed);
+                return Some(offset);
+            }
+        }
+    }
+
+    /// Prepends `offset`'s block to alignment class `aidx`, size class
+    /// `idx`'s free list.
+    fn push_free(&self, aidx: usize, idx: usize, offset: u32) {
+        let head = &self.free_heads[aidx][idx];
+        loop {
+            let packed = head.load(Ordering::Acquire);
+            let (tag, current_offset) = unpack_head(packed);
+
+            // SAFETY: the block at `offset` is being freed by the caller and is
+            // at least `class_size(idx)` (>= 16) bytes, so writing the 4-byte
+            // next-offset word into its first bytes is in-bounds.
+            unsafe { self.write_next(offset, current_offset) };
+
+            let new_packed = pack_head(tag.wrapping_add(1), offset);
+            if head
+                .compare_exchange_weak(packed, new_packed, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                self.free_counts[aidx][idx].fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        }
+    }
+
+    /// Reads the next-offset word (`NO_OFFSET` if none) from the block at `offset`.
+    ///
+    /// # Safety
+    /// `offset` must be a valid, at-least-8-byte block within the arena.
+    unsafe fn read_next(&self, offset: u32) -> u32 {
+        let ptr = unsafe { self.base.as_ptr().add(offset as usize) } as *const u32;
+        unsafe { ptr.read_unaligned() }
+    }
+
+    /// Writes the next-offset word into the block at `offset`.
+    ///
+    /// # Safety
+    /// `offset` must be a valid, at-least-8-byte block within the arena.
+    unsafe fn write_next(&self, offset: u32, next: u32) {
+        let ptr = unsafe { self.base.as_ptr().add(offset as usize) } as *mut u32;
+        unsafe { ptr.write_unaligned(next) };
+    }
+
     /// Resets offset to zero. Caller must ensure no references exist.
     ///
     /// # Safety
     /// The caller must ensure no live references point into the arena.
     pub unsafe fn reset(&self) {
         self.offset.store(0, Ordering::SeqCst);
+        for row in &self.free_heads {
+            for head in row {
+                head.store(pack_head(0, NO_OFFSET), Ordering::SeqCst);
+            }
+        }
+        for row in &self.free_counts {
+            for count in row {
+                count.store(0, Ordering::SeqCst);
+            }
+        }
     }
 
     /// Returns bytes allocated.
@@ -83,7 +292,7 @@ impl Drop for Arena {
             Layout::from_size_align(self.capacity, 16).expect("Invalid layout during deallocation");
 
         unsafe {
-            dealloc(self.base.as_ptr(), layout);
+            dealloc_raw(self.base.as_ptr(), layout);
         }
     }
 }
@@ -123,4 +332,58 @@ mod tests {
         unsafe { arena.reset() };
         assert_eq!(arena.used(), 0);
     }
+
+    #[test]
+    fn test_dealloc_reuses_block() {
+        let arena = Arena::new(1024);
+
+        let ptr1 = arena.alloc(20, 8).unwrap();
+        unsafe { arena.dealloc(ptr1, 20, 8) };
+        let used_after_free = arena.used();
+
+        let ptr2 = arena.alloc(20, 8).unwrap();
+        assert_eq!(ptr1.as_ptr(), ptr2.as_ptr());
+        // Reused from the free list, so the bump offset didn't move.
+        assert_eq!(arena.used(), used_after_free);
+    }
+
+    #[test]
+    fn test_live_excludes_freed_blocks() {
+        let arena = Arena::new(1024);
+
+        let ptr = arena.alloc(20, 8).unwrap();
+        assert_eq!(arena.live(), arena.used());
+
+        unsafe { arena.dealloc(ptr, 20, 8) };
+        assert!(arena.live() < arena.used());
+    }
+
+    #[test]
+    fn test_mixed_alignment_reuse_stays_aligned() {
+        let arena = Arena::new(1024);
+
+        // Free a block bump-allocated at align 1 within a size class, then
+        // request the same size class at align 8: the align-1 free list and
+        // the align-8 free list are segregated, so this must miss the free
+        // list (not hand back the under-aligned block) and bump-allocate a
+        // fresh, correctly aligned one instead.
+        let ptr1 = arena.alloc(20, 1).unwrap();
+        unsafe { arena.dealloc(ptr1, 20, 1) };
+
+        let ptr2 = arena.alloc(20, 8).unwrap();
+        assert_eq!(ptr2.as_ptr() as usize % 8, 0);
+    }
+
+    #[test]
+    fn test_oversized_dealloc_is_noop() {
+        let arena = Arena::new(1 << 20);
+
+        let ptr = arena.alloc(1 << 18, 8).unwrap();
+        let used_before = arena.used();
+        unsafe { arena.dealloc(ptr, 1 << 18, 8) };
+
+        // Above the largest size class: nothing to reclaim, bump offset stays put.
+        assert_eq!(arena.used(), used_before);
+        assert_eq!(arena.live(), used_before);
+    }
 }
@@ -0,0 +1,100 @@
+//! CRC32C (Castagnoli) checksum used to detect corrupt or torn region payloads.
+//!
+//! Uses the SSE4.2 `crc32` instruction when the host supports it, falling back
+//! to a software table otherwise. Both paths implement the same polynomial, so
+//! a checksum computed on one path verifies correctly against the other.
+
+/// Computes the CRC32C checksum of `data`.
+pub fn crc32c(data: &[u8]) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("sse4.2") {
+            return unsafe { crc32c_sse42(data) };
+        }
+    }
+    crc32c_table(data)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse4.2")]
+unsafe fn crc32c_sse42(data: &[u8]) -> u32 {
+    use std::arch::x86_64::{_mm_crc32_u8, _mm_crc32_u64};
+
+    let mut crc: u64 = u64::MAX;
+    let mut chunks = data.chunks_exact(8);
+
+    for chunk in &mut chunks {
+        let word = u64::from_le_bytes(chunk.try_into().unwrap());
+        crc = unsafe { _mm_crc32_u64(crc, word) };
+    }
+    for &byte in chunks.remainder() {
+        crc = unsafe { _mm_crc32_u8(crc as u32, byte) as u64 };
+    }
+
+    !(crc as u32)
+}
+
+/// Software fallback using a precomputed byte-wise table (Castagnoli polynomial,
+/// reversed representation `0x82F63B78`).
+fn crc32c_table(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in data {
+        let index = ((crc ^ byte as u32) & 0xFF) as usize;
+        crc = (crc >> 8) ^ CRC32C_TABLE[index];
+    }
+    !crc
+}
+
+const CRC32C_POLY: u32 = 0x82F6_3B78;
+
+static CRC32C_TABLE: [u32; 256] = generate_table();
+
+const fn generate_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ CRC32C_POLY
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_vector() {
+        // Standard CRC32C check value for the ASCII string "123456789".
+        assert_eq!(crc32c(b"123456789"), 0xE306_9283);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(crc32c(b""), 0);
+    }
+
+    #[test]
+    fn test_table_and_hardware_agree() {
+        let data = b"the quick brown fox jumps over the lazy dog";
+        let table_result = crc32c_table(data);
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("sse4.2") {
+                let hw_result = unsafe { crc32c_sse42(data) };
+                assert_eq!(table_result, hw_result);
+            }
+        }
+        assert_eq!(crc32c(data), table_result);
+    }
+}
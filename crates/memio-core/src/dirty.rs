@@ -0,0 +1,119 @@
+//! Coarse dirty-chunk tracking for incremental readers.
+//!
+//! Readers that only see a monotonic `version` have to re-read an entire
+//! region on any change. [`DirtyBitmap`] tracks which fixed-size chunks of
+//! a region's data area have been touched since it was last reset, so
+//! [`SharedMemoryRegion::read_dirty_since`](crate::SharedMemoryRegion::read_dirty_since)
+//! can hand a reader only the changed chunks instead of the whole payload.
+
+/// Size in bytes of each chunk tracked by a [`DirtyBitmap`].
+pub const DIRTY_CHUNK_SIZE: usize = 4096;
+
+/// Tracks which `DIRTY_CHUNK_SIZE`-byte chunks of a region have been
+/// written since the bitmap was last reset. Once more than half the
+/// chunks are dirty, tracking individual chunks stops paying for itself,
+/// so the bitmap degrades to an `overflowed` state meaning "treat the
+/// whole region as dirty" — this is what keeps `read_dirty_since` correct
+/// even if a reader falls arbitrarily far behind.
+#[derive(Debug)]
+pub struct DirtyBitmap {
+    chunks: Vec<bool>,
+    overflowed: bool,
+    since_version: u64,
+}
+
+impl DirtyBitmap {
+    /// Creates a bitmap sized for a region of `capacity` bytes, with no
+    /// chunks marked dirty yet, tracking changes since `since_version`.
+    pub fn new(capacity: usize, since_version: u64) -> Self {
+        let chunk_count = capacity.div_ceil(DIRTY_CHUNK_SIZE).max(1);
+        Self {
+            chunks: vec![false; chunk_count],
+            overflowed: false,
+            since_version,
+        }
+    }
+
+    /// Marks every chunk touching `offset..offset + len` as dirty,
+    /// overflowing once more than half the tracked chunks are dirty.
+    pub fn mark_range(&mut self, offset: usize, len: usize) {
+        if len == 0 || self.overflowed {
+            return;
+        }
+
+        let first = offset / DIRTY_CHUNK_SIZE;
+        let last = (offset + len - 1) / DIRTY_CHUNK_SIZE;
+        let last = last.min(self.chunks.len().saturating_sub(1));
+        for chunk in &mut self.chunks[first..=last] {
+            *chunk = true;
+        }
+
+        let dirty_count = self.chunks.iter().filter(|c| **c).count();
+        if dirty_count * 2 > self.chunks.len() {
+            self.overflowed = true;
+        }
+    }
+
+    /// The version this bitmap has been tracking changes since.
+    pub fn since_version(&self) -> u64 {
+        self.since_version
+    }
+
+    /// Returns the `(offset, length)` byte ranges of dirty chunks clamped
+    /// to `capacity`, or `None` if the bitmap has overflowed and the whole
+    /// region should be treated as dirty instead.
+    pub fn dirty_ranges(&self, capacity: usize) -> Option<Vec<(usize, usize)>> {
+        if self.overflowed {
+            return None;
+        }
+
+        Some(
+            self.chunks
+                .iter()
+                .enumerate()
+                .filter(|(_, dirty)| **dirty)
+                .map(|(i, _)| {
+                    let start = i * DIRTY_CHUNK_SIZE;
+                    let end = (start + DIRTY_CHUNK_SIZE).min(capacity);
+                    (start, end - start)
+                })
+                .collect(),
+        )
+    }
+
+    /// Clears every dirty bit and restarts tracking from `since_version`.
+    pub fn reset(&mut self, since_version: u64) {
+        self.chunks.iter_mut().for_each(|c| *c = false);
+        self.overflowed = false;
+        self.since_version = since_version;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_only_touched_chunks() {
+        let mut bitmap = DirtyBitmap::new(DIRTY_CHUNK_SIZE * 4, 0);
+        bitmap.mark_range(DIRTY_CHUNK_SIZE, 8);
+        let ranges = bitmap.dirty_ranges(DIRTY_CHUNK_SIZE * 4).unwrap();
+        assert_eq!(ranges, vec![(DIRTY_CHUNK_SIZE, DIRTY_CHUNK_SIZE)]);
+    }
+
+    #[test]
+    fn overflows_past_half_dirty() {
+        let mut bitmap = DirtyBitmap::new(DIRTY_CHUNK_SIZE * 4, 0);
+        bitmap.mark_range(0, DIRTY_CHUNK_SIZE * 3);
+        assert!(bitmap.dirty_ranges(DIRTY_CHUNK_SIZE * 4).is_none());
+    }
+
+    #[test]
+    fn reset_clears_bits_and_rebases_version() {
+        let mut bitmap = DirtyBitmap::new(DIRTY_CHUNK_SIZE * 4, 0);
+        bitmap.mark_range(0, DIRTY_CHUNK_SIZE);
+        bitmap.reset(7);
+        assert_eq!(bitmap.since_version(), 7);
+        assert_eq!(bitmap.dirty_ranges(DIRTY_CHUNK_SIZE * 4).unwrap(), vec![]);
+    }
+}
@@ -39,12 +39,50 @@ pub enum MemioError {
     #[error("Invalid header")]
     InvalidHeader,
 
+    #[error("Range starting at {offset} with length {len} exceeds capacity ({capacity} bytes)")]
+    InvalidRange {
+        offset: usize,
+        len: usize,
+        capacity: usize,
+    },
+
     #[error("Region not found: {0}")]
     NotFound(String),
 
     #[error("Protocol error: {0}")]
     Protocol(String),
 
+    #[error("Channel full: {used} of {capacity} bytes in use")]
+    ChannelFull { used: usize, capacity: usize },
+
+    #[error("Corrupt payload: checksum mismatch")]
+    Corrupt,
+
+    #[error("Read contended: exceeded retry budget waiting for a consistent write")]
+    Contended,
+
+    #[error("Version mismatch: expected {expected}, region is at {actual}")]
+    VersionMismatch { expected: u64, actual: u64 },
+
+    #[error("Permission denied: window '{window}' may not {action} region '{name}'")]
+    PermissionDenied {
+        window: String,
+        name: String,
+        action: &'static str,
+    },
+
+    #[error("Region '{0}' is sealed and cannot be written to")]
+    Sealed(String),
+
+    #[error("Region '{0}' is a read-only handle and cannot be written to")]
+    ReadOnly(String),
+
+    #[error("Corrupt ring buffer: {0}")]
+    CorruptRing(String),
+
+    #[error("io_uring unavailable: {0}")]
+    IoUringUnavailable(String),
+
     #[error("IO error: {0}")]
     Io(String),
 
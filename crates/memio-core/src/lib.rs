@@ -4,6 +4,9 @@ use std::fmt::Debug;
 use std::path::PathBuf;
 
 pub mod arena;
+pub mod channel;
+pub mod crc32c;
+pub mod dirty;
 pub mod error;
 pub mod shared_state;
 pub mod state;
@@ -12,6 +15,9 @@ mod shared_state_spec;
 pub mod shared_header;
 
 pub use arena::Arena;
+pub use channel::{ChannelNotifier, MemioChannel, NoOpNotifier};
+pub use crc32c::crc32c;
+pub use dirty::{DirtyBitmap, DIRTY_CHUNK_SIZE};
 pub use error::{MemioError, MemioResult};
 
 /// Alias for MemioError.
@@ -26,6 +32,11 @@ pub struct SharedStateInfo {
     pub version: u64,
     pub length: usize,
     pub capacity: usize,
+    /// `true` if the region's bytes and version are frozen — created via
+    /// `create_sealed` and sealed against further writes (on Linux, via
+    /// `memfd` `F_SEAL_WRITE`; on Windows, by reopening as a read-only
+    /// view) — so consumers can trust them without re-checking `version`.
+    pub sealed: bool,
 }
 
 /// Interface for memio regions.
@@ -47,6 +58,133 @@ pub trait SharedMemoryRegion: Send + Sync + Debug {
 
     /// Returns mutable pointer to data area.
     unsafe fn data_ptr_mut(&mut self) -> *mut u8;
+
+    /// Reads a `len`-byte window starting at `offset` into the data region,
+    /// copying only that window rather than the whole payload. Bounds-checked
+    /// against `capacity()`.
+    ///
+    /// The default implementation works for any region via [`data_ptr`] and
+    /// applies uniformly across platforms; implementors don't need to
+    /// override it.
+    ///
+    /// [`data_ptr`]: SharedMemoryRegion::data_ptr
+    fn read_at(&self, offset: usize, len: usize) -> Result<Vec<u8>, MemioError> {
+        let capacity = self.capacity();
+        if offset.checked_add(len).is_none_or(|end| end > capacity) {
+            return Err(MemioError::InvalidRange { offset, len, capacity });
+        }
+
+        // SAFETY: `data_ptr` is valid for `capacity` bytes, and the check
+        // above confirms `offset..offset + len` falls within that range.
+        let data = unsafe { std::slice::from_raw_parts(self.data_ptr().add(offset), len) };
+        Ok(data.to_vec())
+    }
+
+    /// Writes `data` into a `data.len()`-byte window starting at `offset`,
+    /// copying only that window, then bumps the header's version atomically
+    /// so readers still observe a consistent `(version, length)` pair. The
+    /// stored length grows to cover the write if it extends past the
+    /// previous one, but never shrinks.
+    ///
+    /// The default implementation relies on the header immediately
+    /// preceding the data region at `data_ptr() - SHARED_STATE_HEADER_SIZE`,
+    /// which holds for every region type in this crate; implementors don't
+    /// need to override it.
+    fn write_at(
+        &mut self,
+        version: u64,
+        offset: usize,
+        data: &[u8],
+    ) -> Result<SharedStateInfo, MemioError> {
+        let capacity = self.capacity();
+        if offset.checked_add(data.len()).is_none_or(|end| end > capacity) {
+            return Err(MemioError::InvalidRange {
+                offset,
+                len: data.len(),
+                capacity,
+            });
+        }
+
+        let mut info = self.info()?;
+        let new_length = info.length.max(offset + data.len());
+
+        // SAFETY: `data_ptr_mut` is valid for `capacity` bytes; the check
+        // above confirms the window falls within it.
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), self.data_ptr_mut().add(offset), data.len());
+        }
+
+        std::sync::atomic::fence(std::sync::atomic::Ordering::Release);
+
+        // SAFETY: the header lives immediately before the data region for
+        // every implementor of this trait (each one's `data_ptr`/
+        // `data_ptr_mut` is its mapping's base pointer plus
+        // `SHARED_STATE_HEADER_SIZE`), so stepping back by the header size
+        // from `data_ptr_mut()` lands on the header's own address.
+        unsafe {
+            shared_header::write_header_ptr(
+                self.data_ptr_mut().sub(shared_state::SHARED_STATE_HEADER_SIZE),
+                version,
+                new_length,
+            );
+        }
+
+        info.version = version;
+        info.length = new_length;
+        Ok(info)
+    }
+
+    /// Returns `(version, ranges)` describing what changed since
+    /// `last_version`: `ranges` is `Some(chunks)` when exactly what changed
+    /// is known, or `None` when it isn't (or changed too much to track
+    /// precisely), meaning the caller should fall back to a full read
+    /// instead of trusting a partial one.
+    ///
+    /// The default implementation has no dirty-tracking state to consult,
+    /// so it always reports `None` — correct, just not incremental.
+    /// Implementors that maintain a [`DirtyBitmap`] (currently just
+    /// `LinuxSharedMemoryRegion`) override this to serve precise ranges
+    /// when `last_version` matches what the bitmap has been tracking.
+    fn read_dirty_since(
+        &self,
+        _last_version: u64,
+    ) -> Result<(u64, Option<Vec<(usize, Vec<u8>)>>), MemioError> {
+        let info = self.info()?;
+        Ok((info.version, None))
+    }
+
+    /// Reads `(version, data)` guarding against observing a write in progress.
+    ///
+    /// A writer following the seqlock convention (see [`shared_header::begin_write`]/
+    /// [`shared_header::end_write`]) holds an odd version while it touches the
+    /// data region; this retries the read until it sees an even version that
+    /// didn't change between reading the data and re-checking it. Bounded by an
+    /// internal retry budget, returning `MemioError::Contended` if exceeded.
+    ///
+    /// The default implementation is best-effort: it works for any region via
+    /// `info()`/`read()` alone, so it's also safe to call on regions whose
+    /// writers don't follow the seqlock convention (it just degrades to a
+    /// plain read-with-version-check).
+    fn read_consistent(&self) -> Result<(u64, Vec<u8>), MemioError> {
+        const MAX_RETRIES: usize = 100;
+
+        for _ in 0..MAX_RETRIES {
+            let before = self.info()?;
+            if before.version & 1 != 0 {
+                std::hint::spin_loop();
+                continue;
+            }
+
+            let data = self.read()?;
+            let after = self.info()?;
+
+            if before.version == after.version {
+                return Ok((before.version, data));
+            }
+        }
+
+        Err(MemioError::Contended)
+    }
 }
 
 /// Interface for creating memio regions.
@@ -59,6 +197,19 @@ pub trait SharedMemoryFactory: Send + Sync {
     /// Opens an existing region by name.
     fn open(&self, name: &str) -> Result<Self::Region, MemioError>;
 
+    /// Opens an existing region by name with a handle that rejects
+    /// `write()`/`write_at()` with [`MemioError::ReadOnly`], so it can be
+    /// handed to a consumer that shouldn't be able to corrupt the buffer.
+    /// Implementors that can enforce this at the kernel level (Linux
+    /// `mmap(PROT_READ)`, Android `ASharedMemory_setProt`) should do so
+    /// rather than only checking a flag in Rust.
+    ///
+    /// The default implementation reports [`MemioError::PlatformNotSupported`]
+    /// for factories that don't yet offer a distinct read-only mapping.
+    fn open_readonly(&self, _name: &str) -> Result<Self::Region, MemioError> {
+        Err(MemioError::PlatformNotSupported)
+    }
+
     /// Lists region names.
     fn list(&self) -> Vec<String>;
 
@@ -74,14 +225,16 @@ pub type BoxedFactory = Box<dyn SharedMemoryFactory<Region = BoxedRegion>>;
 
 pub use shared_state::{SHARED_STATE_HEADER_SIZE, SHARED_STATE_MAGIC};
 pub use schema::{MemioField, MemioFieldType, MemioScalarType, MemioSchema, schema_json};
-pub use state::{MemioState, NoOpRegion};
+pub use state::{ChangeLog, MemioState, NoOpChangeLog, NoOpRegion};
 
 pub use shared_header::{
-    SHARED_STATE_ENDIANNESS, SHARED_STATE_LENGTH_OFFSET, 
+    SHARED_STATE_ENDIANNESS, SHARED_STATE_LENGTH_OFFSET,
     SHARED_STATE_MAGIC_OFFSET, SHARED_STATE_VERSION_OFFSET,
-    validate_magic, validate_magic_result, write_header, write_header_unchecked,
-    read_header, read_version, read_length, read_u64_le, write_u64_le,
-    read_header_ptr, write_header_ptr, read_u64_ptr, write_u64_ptr,
+    SHARED_STATE_CHECKSUM_OFFSET, SHARED_STATE_FLAGS_OFFSET, SHARED_STATE_FLAG_HAS_CHECKSUM,
+    validate_magic, validate_magic_result, write_header, write_header_durable, write_header_unchecked,
+    read_header, read_header_verified, read_version, read_length, read_u64_le, write_u64_le,
+    read_u32_le, write_u32_le, read_header_ptr, write_header_ptr, read_u64_ptr, write_u64_ptr,
+    begin_write, end_write, read_consistent,
 };
 
 pub use memio_macros::MemioModel;
@@ -29,12 +29,47 @@ impl MemioScalarType {
             Self::F64 => "f64",
         }
     }
+
+    /// Size of this scalar type in bytes.
+    pub fn size_bytes(self) -> usize {
+        match self {
+            Self::U8 | Self::I8 => 1,
+            Self::U16 | Self::I16 => 2,
+            Self::U32 | Self::I32 | Self::F32 => 4,
+            Self::U64 | Self::I64 | Self::F64 => 8,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum MemioFieldType {
     Scalar(MemioScalarType),
     Array { elem: MemioScalarType, len: usize },
+    /// A fixed-arity tuple, e.g. `(f32, f32)`. `elems` lists each
+    /// component's scalar type in order; nested tuples/arrays within a
+    /// tuple aren't supported.
+    Tuple { elems: &'static [MemioScalarType] },
+    /// An embedded `#[derive(MemioModel)]` struct, recursing into its own
+    /// schema rather than flattening its fields into the parent.
+    Struct { fields: &'static [MemioField] },
+}
+
+impl MemioFieldType {
+    /// Size of this field in bytes: the scalar's size, element size times
+    /// length for an array, the sum of component sizes for a tuple, or the
+    /// extent of the furthest nested field for a struct.
+    pub fn byte_len(self) -> usize {
+        match self {
+            Self::Scalar(ty) => ty.size_bytes(),
+            Self::Array { elem, len } => elem.size_bytes() * len,
+            Self::Tuple { elems } => elems.iter().map(|ty| ty.size_bytes()).sum(),
+            Self::Struct { fields } => fields
+                .iter()
+                .map(|f| f.offset + f.ty.byte_len())
+                .max()
+                .unwrap_or(0),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -44,14 +79,28 @@ pub struct MemioField {
     pub ty: MemioFieldType,
 }
 
+impl MemioField {
+    /// Byte range `[offset, offset + len)` this field occupies in the
+    /// archived layout.
+    pub fn byte_range(&self) -> (usize, usize) {
+        (self.offset, self.ty.byte_len())
+    }
+}
+
 pub trait MemioSchema {
     fn schema() -> &'static [MemioField];
 }
 
 /// Generates JSON representation of schema fields.
 pub fn schema_json<T: MemioSchema>() -> String {
-    let fields = T::schema();
-    let mut out = String::from("{\"fields\":[");
+    format!("{{\"fields\":{}}}", schema_json_fields(T::schema()))
+}
+
+/// Renders a field list as a JSON array, recursing into nested `Struct`
+/// field types so embedded `#[derive(MemioModel)]` structs show their own
+/// fields rather than just a type name.
+fn schema_json_fields(fields: &[MemioField]) -> String {
+    let mut out = String::from("[");
     for (idx, field) in fields.iter().enumerate() {
         if idx > 0 {
             out.push(',');
@@ -73,9 +122,26 @@ pub fn schema_json<T: MemioSchema>() -> String {
                 out.push_str(&len.to_string());
                 out.push('}');
             }
+            MemioFieldType::Tuple { elems } => {
+                out.push_str(",\"type\":\"tuple\",\"elems\":[");
+                for (i, elem) in elems.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    out.push('"');
+                    out.push_str(elem.as_str());
+                    out.push('"');
+                }
+                out.push_str("]}");
+            }
+            MemioFieldType::Struct { fields } => {
+                out.push_str(",\"type\":\"struct\",\"fields\":");
+                out.push_str(&schema_json_fields(fields));
+                out.push('}');
+            }
         }
     }
-    out.push_str("]}");
+    out.push(']');
     out
 }
 
@@ -1,12 +1,19 @@
 //! Header read/write functions for memio regions.
 
 pub use crate::shared_state_spec::{
-    SHARED_STATE_ENDIANNESS, SHARED_STATE_HEADER_SIZE, SHARED_STATE_LENGTH_OFFSET,
+    SHARED_STATE_CHECKSUM_OFFSET, SHARED_STATE_ENDIANNESS, SHARED_STATE_FLAGS_OFFSET,
+    SHARED_STATE_FLAG_HAS_CHECKSUM, SHARED_STATE_HEADER_SIZE, SHARED_STATE_LENGTH_OFFSET,
     SHARED_STATE_MAGIC, SHARED_STATE_MAGIC_OFFSET, SHARED_STATE_VERSION_OFFSET,
 };
 
+use std::sync::atomic::{fence, Ordering};
+
+use crate::crc32c::crc32c;
 use crate::{MemioError, MemioResult};
 
+/// Maximum number of retries a seqlock-consistent read performs before giving up.
+const SEQLOCK_MAX_RETRIES: usize = 100;
+
 /// Returns true if buffer starts with valid magic bytes.
 pub fn validate_magic(buf: &[u8]) -> bool {
     if buf.len() < SHARED_STATE_HEADER_SIZE {
@@ -31,16 +38,16 @@ pub fn validate_magic_result(buf: &[u8]) -> MemioResult<()> {
     Ok(())
 }
 
-/// Writes magic, version, and length to buffer.
+/// Writes magic, version, and length to buffer, plus a CRC32C of
+/// `buf[SHARED_STATE_HEADER_SIZE..SHARED_STATE_HEADER_SIZE + length]` so a later
+/// [`read_header_verified`] can detect a truncated or partially-synced payload.
 pub fn write_header(buf: &mut [u8], version: u64, length: usize) -> MemioResult<()> {
     if buf.len() < SHARED_STATE_HEADER_SIZE {
         return Err(MemioError::Internal(
             "Shared state header too small.".to_string(),
         ));
     }
-    write_u64_le(buf, SHARED_STATE_MAGIC_OFFSET, SHARED_STATE_MAGIC);
-    write_u64_le(buf, SHARED_STATE_VERSION_OFFSET, version);
-    write_u64_le(buf, SHARED_STATE_LENGTH_OFFSET, length as u64);
+    write_header_checksummed(buf, version, length);
     Ok(())
 }
 
@@ -49,10 +56,135 @@ pub fn write_header_unchecked(buf: &mut [u8], version: u64, length: usize) -> bo
     if buf.len() < SHARED_STATE_HEADER_SIZE {
         return false;
     }
+    write_header_checksummed(buf, version, length);
+    true
+}
+
+/// Shared implementation for `write_header`/`write_header_unchecked`: writes the
+/// magic/version/length words, then the payload's CRC32C and the flag bit that
+/// marks it valid. Callers have already validated `buf.len() >= SHARED_STATE_HEADER_SIZE`.
+fn write_header_checksummed(buf: &mut [u8], version: u64, length: usize) {
     write_u64_le(buf, SHARED_STATE_MAGIC_OFFSET, SHARED_STATE_MAGIC);
     write_u64_le(buf, SHARED_STATE_VERSION_OFFSET, version);
     write_u64_le(buf, SHARED_STATE_LENGTH_OFFSET, length as u64);
-    true
+
+    let payload_start = SHARED_STATE_HEADER_SIZE;
+    let payload_end = (payload_start + length).min(buf.len());
+    let checksum = crc32c(&buf[payload_start..payload_end]);
+    write_u32_le(buf, SHARED_STATE_CHECKSUM_OFFSET, checksum);
+    write_u32_le(buf, SHARED_STATE_FLAGS_OFFSET, SHARED_STATE_FLAG_HAS_CHECKSUM);
+}
+
+/// Writes a header update in crash-consistent order for persistent buffers:
+/// length and checksum first, then the version word last, so a crash between
+/// the two writes can never leave a newer version pointing at a payload that
+/// hasn't actually landed on disk. Magic is assumed to already be set (by the
+/// initial [`write_header_unchecked`] at creation) and is left untouched.
+///
+/// Pair with an `msync`/`flush` of the written range after this returns —
+/// this function only orders the writes within the mapping, it doesn't force
+/// them to stable storage itself.
+pub fn write_header_durable(buf: &mut [u8], version: u64, length: usize) {
+    write_u64_le(buf, SHARED_STATE_LENGTH_OFFSET, length as u64);
+
+    let payload_start = SHARED_STATE_HEADER_SIZE;
+    let payload_end = (payload_start + length).min(buf.len());
+    let checksum = crc32c(&buf[payload_start..payload_end]);
+    write_u32_le(buf, SHARED_STATE_CHECKSUM_OFFSET, checksum);
+    write_u32_le(buf, SHARED_STATE_FLAGS_OFFSET, SHARED_STATE_FLAG_HAS_CHECKSUM);
+
+    write_u64_le(buf, SHARED_STATE_VERSION_OFFSET, version);
+}
+
+/// Begins a seqlock-protected write: bumps the dedicated sequence word at
+/// `seq_offset` (not the version field) to odd, so concurrent readers in any
+/// process mapping this region observe a write in progress.
+///
+/// `seq_offset` is a byte offset into `buf` the caller picks for its own
+/// sequence word, kept separate from `SHARED_STATE_VERSION_OFFSET` on
+/// purpose: `version` carries an arbitrary caller-supplied business value (a
+/// timestamp, a frame counter, ...) that must come back out of a read
+/// exactly as written, so it can't also double as the seqlock's own
+/// odd/even write-in-progress marker — an odd business version would
+/// otherwise make every read of it look like a torn write forever. This
+/// module doesn't reserve a fixed offset itself because `SHARED_STATE_HEADER_SIZE`'s
+/// internal padding isn't something callers here can assume; placing the
+/// word is the caller's responsibility (see `LinuxSharedMemoryRegion`, which
+/// appends it right after the payload region it already tracks).
+///
+/// Callers must write the payload, length, and version only after this
+/// returns, and must follow up with [`end_write`] once the payload is fully
+/// copied in.
+pub fn begin_write(buf: &mut [u8], seq_offset: usize) -> MemioResult<()> {
+    if buf.len() < seq_offset + 8 {
+        return Err(MemioError::Internal(
+            "Shared state header too small.".to_string(),
+        ));
+    }
+    let seq = read_u64_le(buf, seq_offset);
+    write_u64_le(buf, seq_offset, seq.wrapping_add(1));
+    fence(Ordering::Release);
+    Ok(())
+}
+
+/// Ends a seqlock-protected write: bumps the sequence word at `seq_offset`
+/// to the next even value, publishing whatever version/length/payload were
+/// written since [`begin_write`] to any process mapping this region.
+pub fn end_write(buf: &mut [u8], seq_offset: usize) -> MemioResult<()> {
+    if buf.len() < seq_offset + 8 {
+        return Err(MemioError::Internal(
+            "Shared state header too small.".to_string(),
+        ));
+    }
+    fence(Ordering::Release);
+    let seq = read_u64_le(buf, seq_offset);
+    write_u64_le(buf, seq_offset, seq.wrapping_add(1));
+    Ok(())
+}
+
+/// Reads `(version, length)` under the seqlock protocol, retrying while a writer
+/// is in progress (odd sequence word) or while the sequence changes mid-read.
+///
+/// Sources the in-progress/torn-write signal from the sequence word at
+/// `seq_offset` rather than the version field, so this is safe to call
+/// against a mapping opened by an entirely different process than the one
+/// doing the writing — there's no process-local state involved anywhere in
+/// this function, unlike a plain `AtomicU64` kept on the writer's own side.
+///
+/// Returns `Err` if the retry budget is exhausted without observing a stable,
+/// even sequence number twice in a row.
+pub fn read_consistent(buf: &[u8], capacity: usize, seq_offset: usize) -> MemioResult<(u64, usize)> {
+    if buf.len() < seq_offset + 8 {
+        return Err(MemioError::Internal(
+            "Shared state header too small.".to_string(),
+        ));
+    }
+
+    for _ in 0..SEQLOCK_MAX_RETRIES {
+        let seq_before = read_u64_le(buf, seq_offset);
+        if seq_before & 1 != 0 {
+            std::hint::spin_loop();
+            continue;
+        }
+        fence(Ordering::Acquire);
+
+        let version = read_u64_le(buf, SHARED_STATE_VERSION_OFFSET);
+        let length = read_u64_le(buf, SHARED_STATE_LENGTH_OFFSET) as usize;
+
+        fence(Ordering::Acquire);
+        let seq_after = read_u64_le(buf, seq_offset);
+
+        if seq_before == seq_after {
+            if length > capacity {
+                return Err(MemioError::InvalidHeader);
+            }
+            return Ok((version, length));
+        }
+    }
+
+    Err(MemioError::Internal(
+        "Seqlock read exceeded retry budget.".to_string(),
+    ))
 }
 
 /// Reads and validates header. Returns (version, length) if valid.
@@ -71,6 +203,34 @@ pub fn read_header(buf: &[u8], capacity: usize) -> Option<(u64, usize)> {
     Some((version, length))
 }
 
+/// Reads and validates the header like [`read_header`], then recomputes the
+/// payload's CRC32C and compares it against the stored checksum.
+///
+/// Returns `MemioError::Corrupt` on a mismatch, distinguishing a torn/truncated
+/// payload from "no data yet" (an invalid header, which `read_header` already
+/// rejects). A header written before the checksum flag existed (flag bit unset)
+/// is treated as unverifiable and passes through unchecked, so old writers stay
+/// compatible with this reader.
+pub fn read_header_verified(buf: &[u8], capacity: usize) -> MemioResult<(u64, usize)> {
+    let (version, length) = read_header(buf, capacity).ok_or(MemioError::InvalidHeader)?;
+
+    let flags = read_u32_le(buf, SHARED_STATE_FLAGS_OFFSET);
+    if flags & SHARED_STATE_FLAG_HAS_CHECKSUM == 0 {
+        return Ok((version, length));
+    }
+
+    let payload_start = SHARED_STATE_HEADER_SIZE;
+    let payload_end = (payload_start + length).min(buf.len());
+    let expected = read_u32_le(buf, SHARED_STATE_CHECKSUM_OFFSET);
+    let actual = crc32c(&buf[payload_start..payload_end]);
+
+    if expected != actual {
+        return Err(MemioError::Corrupt);
+    }
+
+    Ok((version, length))
+}
+
 /// Reads version from header.
 pub fn read_version(buf: &[u8]) -> Option<u64> {
     if buf.len() < SHARED_STATE_HEADER_SIZE {
@@ -138,6 +298,21 @@ pub fn read_u64_le(buf: &[u8], offset: usize) -> u64 {
     u64::from_le_bytes(bytes)
 }
 
+/// Writes u32 in little-endian at offset.
+#[inline]
+pub fn write_u32_le(buf: &mut [u8], offset: usize, value: u32) {
+    let bytes = value.to_le_bytes();
+    buf[offset..offset + 4].copy_from_slice(&bytes);
+}
+
+/// Reads u32 in little-endian from offset.
+#[inline]
+pub fn read_u32_le(buf: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&buf[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
 /// Writes u64 in little-endian to pointer at offset.
 #[inline]
 /// # Safety
@@ -188,4 +363,96 @@ mod tests {
         write_header_unchecked(&mut buf, 123, 0);
         assert_eq!(read_version(&buf), Some(123));
     }
+
+    #[test]
+    fn test_seqlock_roundtrip() {
+        // Seq word lives in an 8-byte trailer past the payload region, the
+        // way `LinuxSharedMemoryRegion` lays it out, rather than inside the
+        // header itself — see `begin_write`'s doc comment for why.
+        let seq_offset = SHARED_STATE_HEADER_SIZE + 10;
+        let mut buf = vec![0u8; seq_offset + 8];
+
+        begin_write(&mut buf, seq_offset).unwrap();
+        write_u64_le(&mut buf, SHARED_STATE_VERSION_OFFSET, 7);
+        write_u64_le(&mut buf, SHARED_STATE_LENGTH_OFFSET, 10);
+        end_write(&mut buf, seq_offset).unwrap();
+
+        assert_eq!(read_u64_le(&buf, seq_offset), 2);
+        let (version, length) = read_consistent(&buf, 10, seq_offset).unwrap();
+        assert_eq!(version, 7);
+        assert_eq!(length, 10);
+    }
+
+    #[test]
+    fn test_seqlock_rejects_write_in_progress() {
+        let seq_offset = SHARED_STATE_HEADER_SIZE + 10;
+        let mut buf = vec![0u8; seq_offset + 8];
+        begin_write(&mut buf, seq_offset).unwrap();
+
+        let result = read_consistent(&buf, 10, seq_offset);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_header_verified_roundtrip() {
+        let mut buf = vec![0u8; SHARED_STATE_HEADER_SIZE + 10];
+        buf[SHARED_STATE_HEADER_SIZE..].copy_from_slice(b"helloworld");
+        write_header(&mut buf, 1, 10).unwrap();
+
+        let (version, length) = read_header_verified(&buf, 10).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(length, 10);
+    }
+
+    #[test]
+    fn test_read_header_verified_detects_corruption() {
+        let mut buf = vec![0u8; SHARED_STATE_HEADER_SIZE + 10];
+        buf[SHARED_STATE_HEADER_SIZE..].copy_from_slice(b"helloworld");
+        write_header(&mut buf, 1, 10).unwrap();
+
+        // Simulate a torn write: payload changed after the checksum was stored.
+        buf[SHARED_STATE_HEADER_SIZE] = b'X';
+
+        let result = read_header_verified(&buf, 10);
+        assert!(matches!(result, Err(MemioError::Corrupt)));
+    }
+
+    #[test]
+    fn test_read_header_verified_skips_unflagged_header() {
+        let mut buf = vec![0u8; SHARED_STATE_HEADER_SIZE + 10];
+        // A pre-checksum writer: header fields set, flags word left at zero.
+        write_u64_le(&mut buf, SHARED_STATE_MAGIC_OFFSET, SHARED_STATE_MAGIC);
+        write_u64_le(&mut buf, SHARED_STATE_VERSION_OFFSET, 1);
+        write_u64_le(&mut buf, SHARED_STATE_LENGTH_OFFSET, 10);
+
+        let (version, length) = read_header_verified(&buf, 10).unwrap();
+        assert_eq!(version, 1);
+        assert_eq!(length, 10);
+    }
+
+    #[test]
+    fn test_write_header_durable_preserves_magic_and_updates_fields() {
+        let mut buf = vec![0u8; SHARED_STATE_HEADER_SIZE + 10];
+        write_header_unchecked(&mut buf, 1, 0);
+
+        buf[SHARED_STATE_HEADER_SIZE..].copy_from_slice(b"helloworld");
+        write_header_durable(&mut buf, 2, 10);
+
+        assert!(validate_magic(&buf));
+        let (version, length) = read_header_verified(&buf, 10).unwrap();
+        assert_eq!(version, 2);
+        assert_eq!(length, 10);
+    }
+
+    #[test]
+    fn test_seqlock_rejects_oversized_length() {
+        let seq_offset = SHARED_STATE_HEADER_SIZE + 10;
+        let mut buf = vec![0u8; seq_offset + 8];
+        begin_write(&mut buf, seq_offset).unwrap();
+        write_u64_le(&mut buf, SHARED_STATE_LENGTH_OFFSET, 999);
+        end_write(&mut buf, seq_offset).unwrap();
+
+        let result = read_consistent(&buf, 10, seq_offset);
+        assert!(matches!(result, Err(MemioError::InvalidHeader)));
+    }
 }
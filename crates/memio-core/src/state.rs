@@ -9,11 +9,41 @@ use crate::error::{MemioError, MemioResult};
 use crate::schema::{MemioSchema, schema_json};
 
 /// State container with optional memio region binding.
-pub struct MemioState<T, R: SharedMemoryRegion = NoOpRegion> {
+pub struct MemioState<T, R: SharedMemoryRegion = NoOpRegion, C: ChangeLog = NoOpChangeLog> {
     inner: RwLock<T>,
     version: AtomicU64,
     cache: RwLock<Option<(u64, Vec<u8>)>>,
     shared_region: RwLock<Option<R>>,
+    changelog: RwLock<Option<C>>,
+}
+
+/// Sink for the ordered sequence of `(version, serialized bytes)` pairs a
+/// bound [`MemioState::write`] emits, so a peer process can replay every
+/// committed version instead of only ever observing the latest snapshot via
+/// the shared region.
+pub trait ChangeLog: Send + Sync + std::fmt::Debug {
+    /// Appends the just-written version. Implementations should treat this
+    /// as best-effort (e.g. drop the record if the backing log is full)
+    /// rather than ever blocking the writer indefinitely.
+    fn push(&mut self, version: u64, data: &[u8]) -> MemioResult<()>;
+
+    /// Dequeues the next committed `(version, data)` pair in order, or
+    /// `None` if nothing new has been pushed since the last call.
+    fn poll(&mut self) -> MemioResult<Option<(u64, Vec<u8>)>>;
+}
+
+/// No-op changelog used when a [`MemioState`] isn't bound to one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpChangeLog;
+
+impl ChangeLog for NoOpChangeLog {
+    fn push(&mut self, _version: u64, _data: &[u8]) -> MemioResult<()> {
+        Ok(())
+    }
+
+    fn poll(&mut self) -> MemioResult<Option<(u64, Vec<u8>)>> {
+        Ok(None)
+    }
 }
 
 /// Placeholder region when memio region is not used.
@@ -63,6 +93,7 @@ where
             version: AtomicU64::new(0),
             cache: RwLock::new(None),
             shared_region: RwLock::new(None),
+            changelog: RwLock::new(None),
         }
     }
 
@@ -73,11 +104,12 @@ where
             version: self.version,
             cache: self.cache,
             shared_region: RwLock::new(Some(region)),
+            changelog: self.changelog,
         }
     }
 }
 
-impl<T, R: SharedMemoryRegion> MemioState<T, R>
+impl<T, R: SharedMemoryRegion, C: ChangeLog> MemioState<T, R, C>
 where
     T: Archive
         + for<'a> Serialize<
@@ -95,6 +127,29 @@ where
             version: AtomicU64::new(0),
             cache: RwLock::new(None),
             shared_region: RwLock::new(Some(region)),
+            changelog: RwLock::new(None),
+        }
+    }
+
+    /// Binds a changelog to this state. On the writer side, every future
+    /// [`write`](Self::write) pushes `(version, serialized bytes)` onto it
+    /// in addition to overwriting the shared region; on the reader side, a
+    /// `MemioState` bound to the same underlying log can then drain it in
+    /// order with [`poll_changes`](Self::poll_changes). Replaces any
+    /// previously bound changelog.
+    pub fn subscribe(&self, log: C) -> MemioResult<()> {
+        *self.changelog.write()? = Some(log);
+        Ok(())
+    }
+
+    /// Dequeues the next version a peer committed via a bound changelog, in
+    /// commit order. Returns `None` if nothing new has arrived, or if no
+    /// changelog is bound.
+    pub fn poll_changes(&self) -> MemioResult<Option<(u64, Vec<u8>)>> {
+        let mut guard = self.changelog.write()?;
+        match guard.as_mut() {
+            Some(log) => log.poll(),
+            None => Ok(None),
         }
     }
 
@@ -159,15 +214,24 @@ where
         let version = self.version.fetch_add(1, Ordering::SeqCst) + 1;
 
         let shared_enabled = self.shared_region.read()?.is_some();
+        let changelog_enabled = self.changelog.read()?.is_some();
 
-        if shared_enabled {
+        if shared_enabled || changelog_enabled {
             let bytes = serialize_value(&*guard)?;
             if let Ok(mut cache_guard) = self.cache.write() {
                 *cache_guard = Some((version, bytes.clone()));
             }
-            let mut shared_guard = self.shared_region.write()?;
-            if let Some(region) = shared_guard.as_mut() {
-                region.write(version, &bytes)?;
+            if shared_enabled {
+                let mut shared_guard = self.shared_region.write()?;
+                if let Some(region) = shared_guard.as_mut() {
+                    region.write(version, &bytes)?;
+                }
+            }
+            if changelog_enabled {
+                let mut log_guard = self.changelog.write()?;
+                if let Some(log) = log_guard.as_mut() {
+                    log.push(version, &bytes)?;
+                }
             }
         } else if let Ok(mut cache_guard) = self.cache.write() {
             *cache_guard = None;
@@ -198,7 +262,7 @@ where
     }
 }
 
-impl<T, R> Default for MemioState<T, R>
+impl<T, R, C: ChangeLog> Default for MemioState<T, R, C>
 where
     T: Default
         + Archive
@@ -217,6 +281,7 @@ where
             version: AtomicU64::new(0),
             cache: RwLock::new(None),
             shared_region: RwLock::new(None),
+            changelog: RwLock::new(None),
         }
     }
 }
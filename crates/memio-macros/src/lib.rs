@@ -122,7 +122,15 @@ fn field_type_token(ty: &Type) -> Result<proc_macro2::TokenStream, String> {
                 Some("f64") => Ok(
                     quote! { ::memio_core::MemioFieldType::Scalar(::memio_core::MemioScalarType::F64) },
                 ),
-                Some(other) => Err(format!("MemioModel: unsupported field type `{}`", other)),
+                // Not a recognized scalar name — assume it's a nested
+                // `#[derive(MemioModel)]` struct and recurse into its own
+                // schema. If it isn't, the generated `MemioSchema` bound
+                // below fails to resolve and the error points back here.
+                Some(_other) => Ok(quote! {
+                    ::memio_core::MemioFieldType::Struct {
+                        fields: <#path as ::memio_core::MemioSchema>::schema(),
+                    }
+                }),
                 None => Err("MemioModel: unsupported field type".to_string()),
             }
         }
@@ -141,6 +149,17 @@ fn field_type_token(ty: &Type) -> Result<proc_macro2::TokenStream, String> {
                 ::memio_core::MemioFieldType::Array { elem: #elem, len: #len }
             })
         }
+        Type::Tuple(tuple) => {
+            let elems = tuple
+                .elems
+                .iter()
+                .map(scalar_type_token)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(quote! {
+                ::memio_core::MemioFieldType::Tuple { elems: &[#(#elems),*] }
+            })
+        }
         _ => Err("MemioModel: unsupported field type".to_string()),
     }
 }
@@ -16,6 +16,15 @@ use memio_core::{
 
 const HEADER_SIZE: usize = SHARED_STATE_HEADER_SIZE;
 
+/// NDK entry point that isn't exposed by the `ndk` crate's `shared_memory`
+/// wrapper: drops a shared-memory fd's write permission so the kernel
+/// enforces it, used by [`AndroidSharedMemoryFactory::open_readonly`] on a
+/// duplicated consumer fd.
+#[cfg(target_os = "android")]
+extern "C" {
+    fn ASharedMemory_setProt(fd: RawFd, prot: std::os::raw::c_int) -> std::os::raw::c_int;
+}
+
 /// Counter for generating unique region names
 static COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -32,6 +41,13 @@ pub struct AndroidSharedMemoryRegion {
     ptr: *mut u8,
     size: usize,
     capacity: usize,
+    /// `true` for handles returned by [`AndroidSharedMemoryFactory::open_readonly`],
+    /// whose `ptr` is mapped `PROT_READ` only (via `ASharedMemory_setProt` on
+    /// a duplicated fd), and for a registered region after
+    /// [`AndroidSharedMemoryFactory::seal`], whose existing mapping stays
+    /// read-write; either way [`write`](SharedMemoryRegion::write) rejects
+    /// before ever touching the mapping.
+    read_only: bool,
 }
 
 // SAFETY: The pointer is only accessed through synchronized methods
@@ -82,10 +98,14 @@ impl SharedMemoryRegion for AndroidSharedMemoryRegion {
             version,
             length,
             capacity: self.capacity,
+            sealed: false,
         })
     }
 
     fn write(&mut self, version: u64, data: &[u8]) -> Result<SharedStateInfo, SharedMemoryError> {
+        if self.read_only {
+            return Err(SharedMemoryError::ReadOnly(self.name.clone()));
+        }
         if data.len() > self.capacity {
             return Err(SharedMemoryError::DataTooLarge {
                 data_len: data.len(),
@@ -113,6 +133,7 @@ impl SharedMemoryRegion for AndroidSharedMemoryRegion {
             version,
             length: data.len(),
             capacity: self.capacity,
+            sealed: false,
         })
     }
 
@@ -206,6 +227,17 @@ impl SharedMemoryFactory for AndroidSharedMemoryFactory {
 
         let fd = shared_mem.into_raw_fd();
 
+        // Drop PROT_EXEC from the fd's allowed protection set so it can
+        // never be mapped executable by any process that later receives
+        // it (a consumer fd, or this same fd re-mapped after a crash),
+        // before this process's own first mmap below.
+        if unsafe { ASharedMemory_setProt(fd, libc::PROT_READ | libc::PROT_WRITE) } != 0 {
+            unsafe { libc::close(fd) };
+            return Err(SharedMemoryError::CreateFailed(
+                "ASharedMemory_setProt(PROT_READ | PROT_WRITE) failed".to_string(),
+            ));
+        }
+
         // Memory map the region
         let ptr = unsafe {
             libc::mmap(
@@ -240,6 +272,7 @@ impl SharedMemoryFactory for AndroidSharedMemoryFactory {
                 ptr,
                 size: total_size,
                 capacity,
+                read_only: false,
             },
         );
 
@@ -250,6 +283,7 @@ impl SharedMemoryFactory for AndroidSharedMemoryFactory {
             ptr,
             size: total_size,
             capacity,
+            read_only: false,
         })
     }
 
@@ -273,9 +307,66 @@ impl SharedMemoryFactory for AndroidSharedMemoryFactory {
             ptr: region.ptr,
             size: region.size,
             capacity: region.capacity,
+            read_only: region.read_only,
+        })
+    }
+
+    /// Duplicates the consumer fd of an existing region and drops its write
+    /// permission with `ASharedMemory_setProt(fd, PROT_READ)`, then maps that
+    /// duplicate `PROT_READ` only — so even a consumer holding the raw fd
+    /// can't reopen it writable, unlike [`open`](Self::open)'s reference-like
+    /// copy which shares the original read-write mapping.
+    #[cfg(target_os = "android")]
+    fn open_readonly(&self, name: &str) -> Result<Self::Region, SharedMemoryError> {
+        let registry = REGISTRY.lock().unwrap();
+        let region = registry
+            .get(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+
+        let dup_fd = unsafe { libc::dup(region.fd) };
+        if dup_fd < 0 {
+            return Err(SharedMemoryError::Io(
+                std::io::Error::last_os_error().to_string(),
+            ));
+        }
+
+        if unsafe { ASharedMemory_setProt(dup_fd, libc::PROT_READ) } != 0 {
+            unsafe { libc::close(dup_fd) };
+            return Err(SharedMemoryError::CreateFailed(
+                "ASharedMemory_setProt(PROT_READ) failed".to_string(),
+            ));
+        }
+
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                region.size,
+                libc::PROT_READ,
+                libc::MAP_SHARED,
+                dup_fd,
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            unsafe { libc::close(dup_fd) };
+            return Err(SharedMemoryError::MmapFailed);
+        }
+
+        Ok(AndroidSharedMemoryRegion {
+            name: region.name.clone(),
+            fd: dup_fd,
+            ptr: ptr as *mut u8,
+            size: region.size,
+            capacity: region.capacity,
+            read_only: true,
         })
     }
 
+    #[cfg(not(target_os = "android"))]
+    fn open_readonly(&self, _name: &str) -> Result<Self::Region, SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+
     fn list(&self) -> Vec<String> {
         let registry = REGISTRY.lock().unwrap();
         registry.keys().cloned().collect()
@@ -295,6 +386,39 @@ impl SharedMemoryFactory for AndroidSharedMemoryFactory {
     }
 }
 
+impl AndroidSharedMemoryFactory {
+    /// Seals a registered region: narrows its owning fd's allowed
+    /// protections to `PROT_READ` via `ASharedMemory_setProt`, so any
+    /// process that maps it from here on — including one that already
+    /// holds the fd but hasn't mapped it yet — gets a read-only mapping,
+    /// and marks the in-process handle read-only so [`write`](SharedMemoryRegion::write)
+    /// rejects local writes too.
+    ///
+    /// Irreversible, like [`open_readonly`](Self::open_readonly)'s
+    /// narrowing of a duplicated fd: `ASharedMemory_setProt` can only
+    /// restrict a fd's allowed protections further, never widen them back.
+    #[cfg(target_os = "android")]
+    pub fn seal(&self, name: &str) -> Result<(), SharedMemoryError> {
+        let mut registry = REGISTRY.lock().unwrap();
+        let region = registry
+            .get_mut(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+
+        if unsafe { ASharedMemory_setProt(region.fd, libc::PROT_READ) } != 0 {
+            return Err(SharedMemoryError::CreateFailed(
+                "ASharedMemory_setProt(PROT_READ) failed".to_string(),
+            ));
+        }
+        region.read_only = true;
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "android"))]
+    pub fn seal(&self, _name: &str) -> Result<(), SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+}
+
 /// JNI-compatible API
 /// Creates a new memio region and returns its file descriptor.
 ///
@@ -306,6 +430,14 @@ pub fn create_shared_region(name: &str, capacity: usize) -> Result<RawFd, Shared
     get_shared_fd(name)
 }
 
+/// Seals a named memio region against further writes or executable
+/// mappings, so a producer can lock a buffer down before handing its fd to
+/// another component. See [`AndroidSharedMemoryFactory::seal`].
+pub fn seal_shared_region(name: &str) -> Result<(), SharedMemoryError> {
+    let factory = AndroidSharedMemoryFactory::new();
+    factory.seal(name)
+}
+
 /// Writes data to a named memio region.
 pub fn write_to_shared(name: &str, version: u64, data: &[u8]) -> Result<(), SharedMemoryError> {
     let mut registry = REGISTRY.lock().unwrap();
@@ -358,3 +490,29 @@ pub fn has_shared_region(name: &str) -> bool {
     let registry = REGISTRY.lock().unwrap();
     registry.contains_key(name)
 }
+
+/// Creates an `eventfd` for waking a `MemioChannel` reader on the Kotlin side.
+///
+/// The Rust side of a channel notifies readers with this fd's counter
+/// (`libc::write`); the fd is handed to Kotlin alongside the region's
+/// `DirectByteBuffer` so the UI thread can `select`/poll on it instead of
+/// spinning on the channel's `head`/`tail` words.
+///
+/// This is the JNI-facing entry point; non-Android builds report
+/// [`SharedMemoryError::PlatformNotSupported`].
+#[cfg(target_os = "android")]
+pub fn create_channel_eventfd() -> Result<RawFd, SharedMemoryError> {
+    let fd = unsafe { libc::eventfd(0, libc::EFD_CLOEXEC | libc::EFD_NONBLOCK) };
+    if fd < 0 {
+        return Err(SharedMemoryError::CreateFailed(format!(
+            "eventfd failed: {}",
+            std::io::Error::last_os_error()
+        )));
+    }
+    Ok(fd)
+}
+
+#[cfg(not(target_os = "android"))]
+pub fn create_channel_eventfd() -> Result<RawFd, SharedMemoryError> {
+    Err(SharedMemoryError::PlatformNotSupported)
+}
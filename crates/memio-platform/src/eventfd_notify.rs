@@ -0,0 +1,133 @@
+//! `eventfd`-backed wake-up primitive for `MemioManager::wait_for_change`.
+//!
+//! Distinct from `futex_notify`'s `ChannelNotifier` (which wakes a single
+//! `MemioChannel` reader spinning on a notify word already in shared
+//! memory): this wakes `MemioManager` readers blocked on a named buffer's
+//! version. Each buffer in the registry owns one `EventFd`; a writer bumps
+//! it after every write, and a blocked reader waits on it through a
+//! single-fd epoll set instead of polling `version()` in a loop.
+
+use std::io;
+use std::os::fd::RawFd;
+use std::time::Duration;
+
+/// A Linux `eventfd(2)` used to wake one or more blocked readers after a
+/// writer bumps a buffer's version.
+#[derive(Debug)]
+pub struct EventFd(RawFd);
+
+impl EventFd {
+    /// Creates a new counter-mode eventfd (`EFD_NONBLOCK`): each `notify`
+    /// adds 1, and a single drain reads back the accumulated count, so a
+    /// burst of writes between waits coalesces into one wake rather than
+    /// being lost or queued.
+    pub fn new() -> io::Result<Self> {
+        let fd = unsafe { libc::eventfd(0, libc::EFD_NONBLOCK | libc::EFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self(fd))
+    }
+
+    /// Increments the eventfd's counter by 1, waking any blocked waiter.
+    pub fn notify(&self) {
+        let value: u64 = 1;
+        unsafe {
+            libc::write(
+                self.0,
+                &value as *const u64 as *const libc::c_void,
+                std::mem::size_of::<u64>(),
+            );
+        }
+    }
+
+    /// The raw descriptor, for registering in an epoll set.
+    pub fn raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+impl Drop for EventFd {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.0);
+        }
+    }
+}
+
+// Safety: the fd is only ever touched through the syscalls above, all of
+// which are safe to call from any thread.
+unsafe impl Send for EventFd {}
+unsafe impl Sync for EventFd {}
+
+/// Blocks on `fd` through a single-fd epoll set for up to `timeout`,
+/// draining its counter on wake so the next call doesn't return immediately
+/// on a notify already observed. Returns `Ok(true)` if `fd` became readable
+/// (a notify arrived), `Ok(false)` on timeout, `Err` if epoll itself failed.
+pub fn epoll_wait_eventfd(fd: RawFd, timeout: Duration) -> io::Result<bool> {
+    let epfd = unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) };
+    if epfd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut event = libc::epoll_event {
+        events: libc::EPOLLIN as u32,
+        u64: fd as u64,
+    };
+    let ctl = unsafe { libc::epoll_ctl(epfd, libc::EPOLL_CTL_ADD, fd, &mut event) };
+    if ctl < 0 {
+        let err = io::Error::last_os_error();
+        unsafe { libc::close(epfd) };
+        return Err(err);
+    }
+
+    let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+    let mut events = [libc::epoll_event { events: 0, u64: 0 }; 1];
+    let n = unsafe { libc::epoll_wait(epfd, events.as_mut_ptr(), 1, timeout_ms) };
+    unsafe { libc::close(epfd) };
+
+    if n < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    if n == 0 {
+        return Ok(false);
+    }
+
+    let mut drain = [0u8; 8];
+    unsafe {
+        libc::read(fd, drain.as_mut_ptr() as *mut libc::c_void, drain.len());
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_epoll_wait_wakes_on_notify() {
+        let event = Arc::new(EventFd::new().unwrap());
+        let fd = event.raw_fd();
+
+        let notifier = event.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            notifier.notify();
+        });
+
+        let woke = epoll_wait_eventfd(fd, Duration::from_secs(2)).unwrap();
+        assert!(woke);
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_epoll_wait_times_out() {
+        let event = EventFd::new().unwrap();
+        let woke = epoll_wait_eventfd(event.raw_fd(), Duration::from_millis(50)).unwrap();
+        assert!(!woke);
+    }
+}
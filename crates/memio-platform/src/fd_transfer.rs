@@ -0,0 +1,128 @@
+//! Transfers a shared-memory file descriptor between processes over an
+//! `AF_UNIX` socket using `SCM_RIGHTS` ancillary messages.
+//!
+//! Pairs with `MemioManager::export_fd`/`import_buffer_from_fd`: a process
+//! that owns a buffer sends its fd to a peer (a sandboxed helper, or a
+//! second Tauri window living in its own process), and the peer maps it in
+//! without re-creating the underlying storage.
+
+use std::io;
+use std::mem;
+use std::os::fd::{AsRawFd, RawFd};
+use std::os::unix::net::UnixStream;
+
+/// Sends `fd` over `socket` as an `SCM_RIGHTS` ancillary message. A single
+/// zero byte rides along as the regular payload, since a control message
+/// can't be sent on its own.
+pub fn send_fd(socket: &UnixStream, fd: RawFd) -> io::Result<()> {
+    let payload = [0u8; 1];
+    let mut iov = libc::iovec {
+        iov_base: payload.as_ptr() as *mut libc::c_void,
+        iov_len: payload.len(),
+    };
+
+    let cmsg_space = unsafe { libc::CMSG_SPACE(mem::size_of::<RawFd>() as u32) } as usize;
+    let mut cmsg_buf = vec![0u8; cmsg_space];
+
+    let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = cmsg_buf.as_mut_ptr() as *mut libc::c_void;
+    msg.msg_controllen = cmsg_space as _;
+
+    // SAFETY: `cmsg_buf` is sized by `CMSG_SPACE` for exactly one fd, and
+    // `CMSG_FIRSTHDR`/`CMSG_DATA` are given the same `msg` whose
+    // `msg_control`/`msg_controllen` point at it.
+    unsafe {
+        let cmsg = libc::CMSG_FIRSTHDR(&msg);
+        (*cmsg).cmsg_level = libc::SOL_SOCKET;
+        (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+        (*cmsg).cmsg_len = libc::CMSG_LEN(mem::size_of::<RawFd>() as u32) as _;
+        std::ptr::write(libc::CMSG_DATA(cmsg) as *mut RawFd, fd);
+    }
+
+    let sent = unsafe { libc::sendmsg(socket.as_raw_fd(), &msg, 0) };
+    if sent < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Marker so callers (`registry_server`, `MemioManager::import_buffer_from_fd`)
+/// can tell "the peer sent a message with no attached fd" apart from an
+/// ordinary OS-level I/O failure and map it to `MemioError::Protocol`
+/// instead of `MemioError::Io`.
+pub const ERR_NO_FD: &str = "no SCM_RIGHTS fd in received message";
+
+/// Receives a single fd sent via `send_fd` over `socket`. The returned fd is
+/// owned by the caller and close-on-exec, so it isn't leaked into a child
+/// process spawned before the caller gets a chance to use or drop it.
+pub fn recv_fd(socket: &UnixStream) -> io::Result<RawFd> {
+    let mut payload = [0u8; 1];
+    let mut iov = libc::iovec {
+        iov_base: payload.as_mut_ptr() as *mut libc::c_void,
+        iov_len: payload.len(),
+    };
+
+    let cmsg_space = unsafe { libc::CMSG_SPACE(mem::size_of::<RawFd>() as u32) } as usize;
+    let mut cmsg_buf = vec![0u8; cmsg_space];
+
+    let mut msg: libc::msghdr = unsafe { mem::zeroed() };
+    msg.msg_iov = &mut iov;
+    msg.msg_iovlen = 1;
+    msg.msg_control = cmsg_buf.as_mut_ptr() as *mut libc::c_void;
+    msg.msg_controllen = cmsg_space as _;
+
+    let received = unsafe { libc::recvmsg(socket.as_raw_fd(), &mut msg, libc::MSG_CMSG_CLOEXEC) };
+    if received < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // SAFETY: `msg` was populated by the `recvmsg` call above, so its
+    // control buffer (if any) is in a state `CMSG_FIRSTHDR`/`CMSG_DATA`
+    // can walk.
+    unsafe {
+        let cmsg = libc::CMSG_FIRSTHDR(&msg);
+        if cmsg.is_null()
+            || (*cmsg).cmsg_level != libc::SOL_SOCKET
+            || (*cmsg).cmsg_type != libc::SCM_RIGHTS
+        {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, ERR_NO_FD));
+        }
+        Ok(std::ptr::read(libc::CMSG_DATA(cmsg) as *const RawFd))
+    }
+}
+
+/// Maps an error from `recv_fd` to `MemioError`, distinguishing "the peer
+/// didn't attach an fd" (a protocol violation) from an ordinary OS-level
+/// failure (closed socket, interrupted call, etc).
+pub fn recv_fd_err(e: io::Error) -> memio_core::MemioError {
+    if e.kind() == io::ErrorKind::InvalidData && e.to_string() == ERR_NO_FD {
+        memio_core::MemioError::Protocol(ERR_NO_FD.to_string())
+    } else {
+        memio_core::MemioError::Io(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::fd::IntoRawFd;
+
+    #[test]
+    fn test_send_and_recv_fd() {
+        let (sender, receiver) = UnixStream::pair().unwrap();
+
+        let file = std::fs::File::open("/dev/null").unwrap();
+        let fd = file.into_raw_fd();
+
+        send_fd(&sender, fd).unwrap();
+        let received_fd = recv_fd(&receiver).unwrap();
+
+        assert_ne!(received_fd, fd);
+        unsafe {
+            libc::close(fd);
+            libc::close(received_fd);
+        }
+    }
+}
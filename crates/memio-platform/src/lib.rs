@@ -29,12 +29,28 @@ pub mod android;
 
 #[cfg(target_os = "windows")]
 pub mod windows;
+#[cfg(target_os = "windows")]
+pub mod windows_pipe_transfer;
 
 // Platform-specific utilities (Linux only for now)
 #[cfg(target_os = "linux")]
 pub mod shared_file;
 #[cfg(target_os = "linux")]
 pub mod shared_ring;
+#[cfg(target_os = "linux")]
+pub mod linux_memfd;
+#[cfg(target_os = "linux")]
+pub mod futex_notify;
+#[cfg(target_os = "linux")]
+pub mod eventfd_notify;
+#[cfg(target_os = "linux")]
+pub mod fd_transfer;
+#[cfg(target_os = "linux")]
+pub mod registry_watcher;
+#[cfg(target_os = "linux")]
+pub mod registry_server;
+#[cfg(target_os = "linux")]
+pub mod io_uring_writer;
 
 // High-level helpers
 pub mod memio_shared;
@@ -155,19 +171,22 @@ pub fn platform_factory() -> ! {
 
 // Re-exports for convenience
 #[cfg(target_os = "linux")]
-pub use linux::{LinuxSharedMemoryFactory, LinuxSharedMemoryRegion, cleanup_orphaned_files};
+pub use linux::{
+    ExportToken, LinuxSharedMemoryFactory, LinuxSharedMemoryRegion, RegionHandle,
+    cleanup_orphaned_files,
+};
 
 #[cfg(target_os = "android")]
 pub use android::{AndroidSharedMemoryFactory, AndroidSharedMemoryRegion};
 
 #[cfg(target_os = "windows")]
-pub use windows::{WindowsSharedMemoryFactory, WindowsSharedMemoryRegion};
+pub use windows::{ExportToken as WindowsExportToken, WindowsSharedMemoryFactory, WindowsSharedMemoryRegion};
 
 // Android JNI-compatible functions
 #[cfg(target_os = "android")]
 pub use android::{
-    create_shared_region, get_shared_fd, get_shared_ptr, has_shared_region, list_shared_regions,
-    read_from_shared, write_to_shared,
+    create_channel_eventfd, create_shared_region, get_shared_fd, get_shared_ptr,
+    has_shared_region, list_shared_regions, read_from_shared, seal_shared_region, write_to_shared,
 };
 
 // Windows helper functions (similar API to Android)
@@ -181,7 +200,19 @@ pub use windows::{
 #[cfg(target_os = "linux")]
 pub use shared_file::SharedFileCache;
 #[cfg(target_os = "linux")]
-pub use shared_ring::SharedRingBuffer;
+pub use shared_ring::{Claim, RingCredits, SharedRingBuffer};
+#[cfg(target_os = "linux")]
+pub use linux_memfd::{MemfdSharedMemoryFactory, MemfdSharedMemoryRegion};
+#[cfg(target_os = "linux")]
+pub use futex_notify::FutexNotifier;
+#[cfg(target_os = "linux")]
+pub use eventfd_notify::EventFd;
+#[cfg(target_os = "linux")]
+pub use registry_watcher::{RegistryEvent, RegistryWatcher};
+#[cfg(target_os = "linux")]
+pub use registry_server::{RegistryClient, RegistryServer};
+#[cfg(target_os = "linux")]
+pub use io_uring_writer::{BatchWriter, IoUringConfig};
 
 // High-level helpers
 pub mod memio_manager;
@@ -193,8 +224,8 @@ pub use registry::SharedRegistry;
 
 // Re-export core contracts
 pub use memio_core::{
-    BoxedFactory, BoxedRegion, SharedMemoryError, SharedMemoryFactory, SharedMemoryRegion,
-    SharedStateInfo,
+    BoxedFactory, BoxedRegion, ChangeLog, NoOpChangeLog, SharedMemoryError, SharedMemoryFactory,
+    SharedMemoryRegion, SharedStateInfo,
 };
 
 // Re-export header constants
@@ -4,20 +4,33 @@
 
 use std::collections::HashMap;
 use std::fs::{self, OpenOptions};
+use std::io;
+use std::os::fd::AsRawFd;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicU64, Ordering};
 
-use memmap2::MmapMut;
+use memmap2::{Mmap, MmapMut};
 use once_cell::sync::Lazy;
 
 use memio_core::{
-    SHARED_STATE_HEADER_SIZE, SharedMemoryError, SharedMemoryFactory, SharedMemoryRegion,
-    SharedStateInfo, read_header, validate_magic, write_header_unchecked,
+    DirtyBitmap, SHARED_STATE_HEADER_SIZE, SharedMemoryError, SharedMemoryFactory,
+    SharedMemoryRegion, SharedStateInfo, read_header, read_version, validate_magic,
+    write_header_durable, write_header_unchecked,
 };
 
 const HEADER_SIZE: usize = SHARED_STATE_HEADER_SIZE;
 
+/// Size of the seqlock sequence word appended right after the payload
+/// region, at offset `HEADER_SIZE + capacity`. Lives there rather than
+/// inside the header itself because the header's own byte layout has no
+/// guaranteed spare padding for it (see `shared_header::begin_write`'s doc
+/// comment); every mapping this module creates or opens is sized to include
+/// this trailer, and it's what every `RegionHandle` seqlock operation reads
+/// and writes instead of a process-local counter, so the same sequence word
+/// is visible to every process mapping the region (see `import_fd`).
+const SEQ_SIZE: usize = 8;
+
 /// Counter for generating unique file names
 static COUNTER: AtomicU64 = AtomicU64::new(0);
 
@@ -98,13 +111,142 @@ fn is_process_running(pid: u32) -> bool {
     Path::new(&format!("/proc/{}", pid)).exists()
 }
 
+/// Process-local pointer to a region's header, data, and seqlock trailer,
+/// shared between the owning `LinuxSharedMemoryRegion` and any reader that
+/// wants seqlock-consistent access without going through `SharedRegistry`'s
+/// mutex for the whole operation — see `MemioManager::read`/`version`,
+/// which cache this handle instead of locking the registry on every call.
+///
+/// The seqlock's sequence word itself lives in the shared mapping (the
+/// trailing [`SEQ_SIZE`] bytes at `HEADER_SIZE + capacity`), not in this
+/// struct, so that every process mapping the same region — including one
+/// that received its fd via `import_fd` rather than creating the region —
+/// observes the same counter. It's independent of the header's own
+/// `version` field: `version` is the caller-supplied business version
+/// (timestamps, frame counters, ...) and is passed through untouched; the
+/// sequence word exists purely so a reader can detect a write in progress
+/// (odd) or torn across its own two checks (mismatched before/after).
+#[derive(Debug)]
+pub struct RegionHandle {
+    ptr: *mut u8,
+    capacity: usize,
+    /// Coarse dirty-chunk tracker backing `read_dirty_since`, guarded by
+    /// its own mutex since it's updated on the write path and read
+    /// independently of the mapping's sequence word.
+    dirty: Mutex<DirtyBitmap>,
+}
+
+// SAFETY: `ptr` points into the mmap owned by the `LinuxSharedMemoryRegion`
+// this handle was cloned from, which keeps the mapping alive for at least
+// as long as any clone of the handle (the region only drops once nothing
+// holds it, and the registry entry outlives the handles cached from it).
+// All access to `ptr` goes through the seqlock protocol below.
+unsafe impl Send for RegionHandle {}
+unsafe impl Sync for RegionHandle {}
+
+impl RegionHandle {
+    /// Size of the mapping this handle's seqlock functions need a view
+    /// over: header, payload, and the trailing sequence word.
+    fn mapped_len(&self) -> usize {
+        HEADER_SIZE + self.capacity + SEQ_SIZE
+    }
+
+    /// Byte offset of the seqlock's sequence word within the mapping.
+    fn seq_offset(&self) -> usize {
+        HEADER_SIZE + self.capacity
+    }
+
+    /// Marks a write as in progress (bumps the mapping's sequence word to odd).
+    fn begin_write(&self) {
+        // SAFETY: `ptr` points at a live mapping of at least `mapped_len()`
+        // bytes (every construction site sizes the file/mmap to include the
+        // seqlock trailer); this handle has exclusive access to the write
+        // path via `LinuxSharedMemoryRegion`'s own `&mut self` methods.
+        let buf = unsafe { std::slice::from_raw_parts_mut(self.ptr, self.mapped_len()) };
+        memio_core::begin_write(buf, self.seq_offset())
+            .expect("region mapping sized for the seqlock trailer");
+    }
+
+    /// Marks a write as complete (bumps the mapping's sequence word to even).
+    fn end_write(&self) {
+        // SAFETY: see `begin_write`.
+        let buf = unsafe { std::slice::from_raw_parts_mut(self.ptr, self.mapped_len()) };
+        memio_core::end_write(buf, self.seq_offset())
+            .expect("region mapping sized for the seqlock trailer");
+    }
+
+    /// Reads `(version, data)` under the seqlock, retrying while a write is
+    /// in progress or the payload changed mid-copy. Returns
+    /// `SharedMemoryError::Contended` if the retry budget is exhausted.
+    ///
+    /// Sources the in-progress/torn-write signal from the mapping's own
+    /// sequence word, so this is safe even when the writer is a different
+    /// process than this reader's (e.g. after `import_fd`).
+    fn read_consistent(&self) -> Result<(u64, Vec<u8>), SharedMemoryError> {
+        // SAFETY: `ptr` points at a live mapping of at least `mapped_len()`
+        // bytes; we only ever read through it.
+        let buf = unsafe { std::slice::from_raw_parts(self.ptr, self.mapped_len()) };
+        let (version, length) = memio_core::read_consistent(buf, self.capacity, self.seq_offset())?;
+        let data = buf[HEADER_SIZE..HEADER_SIZE + length].to_vec();
+        Ok((version, data))
+    }
+
+    /// Reads just the version under the seqlock, without copying the
+    /// payload.
+    fn version_consistent(&self) -> Result<u64, SharedMemoryError> {
+        // SAFETY: see `read_consistent`.
+        let buf = unsafe { std::slice::from_raw_parts(self.ptr, self.mapped_len()) };
+        let (version, _) = memio_core::read_consistent(buf, self.capacity, self.seq_offset())?;
+        Ok(version)
+    }
+}
+
+/// Either a writable or a read-only memory mapping. [`LinuxSharedMemoryFactory::open_readonly`]
+/// produces the latter by mapping the reopened fd with `PROT_READ` only, so
+/// the kernel itself rejects any write attempt rather than relying solely on
+/// the [`LinuxSharedMemoryRegion::write`]/`write_at` guard below.
+#[derive(Debug)]
+enum Mapping {
+    Writable(MmapMut),
+    ReadOnly(Mmap),
+}
+
+impl Mapping {
+    fn as_ptr(&self) -> *const u8 {
+        match self {
+            Mapping::Writable(m) => m.as_ptr(),
+            Mapping::ReadOnly(m) => m.as_ptr(),
+        }
+    }
+}
+
+impl std::ops::Deref for Mapping {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Mapping::Writable(m) => m,
+            Mapping::ReadOnly(m) => m,
+        }
+    }
+}
+
 /// Linux memio region using memory-mapped files.
 #[derive(Debug)]
 pub struct LinuxSharedMemoryRegion {
     name: String,
     path: PathBuf,
-    mmap: MmapMut,
+    /// Kept alive so the region's fd can be exported to another process
+    /// (see `MemioManager::export_fd`); otherwise only the mapping itself
+    /// would be needed.
+    file: fs::File,
+    mmap: Mapping,
     capacity: usize,
+    handle: Arc<RegionHandle>,
+    /// `true` for handles returned by [`LinuxSharedMemoryFactory::open_readonly`];
+    /// [`write`](SharedMemoryRegion::write)/`write_at` reject these before
+    /// touching `mmap`, which is itself mapped `PROT_READ` only.
+    read_only: bool,
 }
 
 impl LinuxSharedMemoryRegion {
@@ -117,6 +259,148 @@ impl LinuxSharedMemoryRegion {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Clones this region's lock-free read handle, for callers (like
+    /// `MemioManager`) that want seqlock-consistent reads without taking
+    /// the registry's mutex for the whole operation.
+    pub fn handle(&self) -> Arc<RegionHandle> {
+        Arc::clone(&self.handle)
+    }
+
+    /// Duplicates this region's backing file descriptor, for handing to
+    /// another process over `SCM_RIGHTS` (see `fd_transfer`). The
+    /// duplicate has its own lifetime independent of this region's.
+    pub fn export_fd(&self) -> io::Result<std::os::fd::RawFd> {
+        let dup = unsafe { libc::dup(self.file.as_raw_fd()) };
+        if dup < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(dup)
+    }
+
+    /// Starts a batch of writes that share a single `io_uring`-submitted
+    /// flush instead of paying an `msync` per call, for callers pushing many
+    /// small versioned snapshots per second. See [`crate::io_uring_writer`]
+    /// for why this is a flush-batching optimization rather than a
+    /// write-batching one, and falls back with
+    /// [`SharedMemoryError::IoUringUnavailable`] rather than failing the
+    /// write path outright when `io_uring` isn't available; callers should
+    /// fall back to plain [`write`](SharedMemoryRegion::write) in that case.
+    pub fn begin_batch(
+        &mut self,
+        config: crate::io_uring_writer::IoUringConfig,
+    ) -> Result<crate::io_uring_writer::BatchWriter<'_>, SharedMemoryError> {
+        crate::io_uring_writer::BatchWriter::new(self, config)
+    }
+
+    /// Writes `data` in crash-consistent order for a persistent (file-backed)
+    /// region: payload bytes, then length and checksum, then the version
+    /// word last (see [`write_header_durable`]), followed by an `msync` of
+    /// the whole mapping, seqlock trailer included. A crash between these steps can never expose
+    /// a newer version pointing at a payload that hasn't actually landed.
+    ///
+    /// Used by `MemioManager::write` for buffers created via
+    /// `create_persistent_buffer`; volatile buffers keep using the ordinary
+    /// [`SharedMemoryRegion::write`], where this ordering doesn't matter.
+    pub fn write_durable(&mut self, version: u64, data: &[u8]) -> Result<SharedStateInfo, SharedMemoryError> {
+        if self.read_only {
+            return Err(SharedMemoryError::ReadOnly(self.name.clone()));
+        }
+        if data.len() > self.capacity {
+            return Err(SharedMemoryError::DataTooLarge {
+                data_len: data.len(),
+                capacity: self.capacity,
+            });
+        }
+
+        let Mapping::Writable(mmap) = &mut self.mmap else {
+            unreachable!("read_only checked above")
+        };
+
+        self.handle.begin_write();
+
+        let data_offset = HEADER_SIZE;
+        mmap[data_offset..data_offset + data.len()].copy_from_slice(data);
+        write_header_durable(mmap, version, data.len());
+
+        self.handle.end_write();
+        self.handle.dirty.lock().unwrap().mark_range(0, data.len());
+
+        // Flushes through the seqlock trailer too (not just the header and
+        // payload), so a crash never leaves the trailer's sequence word
+        // stuck odd on disk — which would make every future reader of this
+        // reopened region see a write in progress forever.
+        mmap.flush_range(0, HEADER_SIZE + self.capacity + SEQ_SIZE)
+            .map_err(|e| SharedMemoryError::Io(e.to_string()))?;
+
+        Ok(SharedStateInfo {
+            name: self.name.clone(),
+            path: Some(self.path.clone()),
+            fd: Some(self.file.as_raw_fd()),
+            version,
+            length: data.len(),
+            capacity: self.capacity,
+            sealed: false,
+        })
+    }
+
+    /// Forces the entire mapping to stable storage via `msync(MS_SYNC)`.
+    /// `write_durable` already flushes the range it touches, so this is for
+    /// callers that want an explicit sync point (e.g. before exiting).
+    pub fn flush(&self) -> Result<(), SharedMemoryError> {
+        match &self.mmap {
+            Mapping::Writable(mmap) => mmap
+                .flush()
+                .map_err(|e| SharedMemoryError::Io(e.to_string())),
+            // Nothing dirty to flush on a read-only view.
+            Mapping::ReadOnly(_) => Ok(()),
+        }
+    }
+
+    /// Does everything [`write`](SharedMemoryRegion::write) does — bounds
+    /// check, seqlock-guarded header/payload update, dirty tracking — except
+    /// the trailing `msync`. [`write`](SharedMemoryRegion::write) is just
+    /// this plus an immediate flush; [`crate::io_uring_writer::BatchWriter`]
+    /// uses this directly so several calls can share one flush at
+    /// `submit_batch`, instead of paying `msync` once per call and then
+    /// again in the batch's own `sync_file_range`.
+    pub(crate) fn write_no_flush(&mut self, version: u64, data: &[u8]) -> Result<SharedStateInfo, SharedMemoryError> {
+        if self.read_only {
+            return Err(SharedMemoryError::ReadOnly(self.name.clone()));
+        }
+        if data.len() > self.capacity {
+            return Err(SharedMemoryError::DataTooLarge {
+                data_len: data.len(),
+                capacity: self.capacity,
+            });
+        }
+
+        let Mapping::Writable(mmap) = &mut self.mmap else {
+            unreachable!("read_only checked above")
+        };
+
+        // Seqlock: mark a write in progress before touching the mapping so
+        // a concurrent `RegionHandle::read_consistent` retries instead of
+        // reading a torn payload.
+        self.handle.begin_write();
+
+        let data_offset = HEADER_SIZE;
+        mmap[data_offset..data_offset + data.len()].copy_from_slice(data);
+        write_header_unchecked(mmap, version, data.len());
+
+        self.handle.end_write();
+        self.handle.dirty.lock().unwrap().mark_range(0, data.len());
+
+        Ok(SharedStateInfo {
+            name: self.name.clone(),
+            path: Some(self.path.clone()),
+            fd: Some(self.file.as_raw_fd()),
+            version,
+            length: data.len(),
+            capacity: self.capacity,
+            sealed: false,
+        })
+    }
 }
 
 impl Drop for LinuxSharedMemoryRegion {
@@ -149,41 +433,24 @@ impl SharedMemoryRegion for LinuxSharedMemoryRegion {
         Ok(SharedStateInfo {
             name: self.name.clone(),
             path: Some(self.path.clone()),
-            fd: None,
+            fd: Some(self.file.as_raw_fd()),
             version,
             length,
             capacity: self.capacity,
+            sealed: false,
         })
     }
 
     fn write(&mut self, version: u64, data: &[u8]) -> Result<SharedStateInfo, SharedMemoryError> {
-        if data.len() > self.capacity {
-            return Err(SharedMemoryError::DataTooLarge {
-                data_len: data.len(),
-                capacity: self.capacity,
-            });
-        }
-
-        // Write data after header
-        let data_offset = HEADER_SIZE;
-        self.mmap[data_offset..data_offset + data.len()].copy_from_slice(data);
-
-        // Write header (includes magic, version, length)
-        write_header_unchecked(&mut self.mmap, version, data.len());
+        let info = self.write_no_flush(version, data)?;
 
         // Ensure changes are visible
-        self.mmap
-            .flush()
-            .map_err(|e| SharedMemoryError::Io(e.to_string()))?;
+        let Mapping::Writable(mmap) = &mut self.mmap else {
+            unreachable!("write_no_flush already checked read_only")
+        };
+        mmap.flush().map_err(|e| SharedMemoryError::Io(e.to_string()))?;
 
-        Ok(SharedStateInfo {
-            name: self.name.clone(),
-            path: Some(self.path.clone()),
-            fd: None,
-            version,
-            length: data.len(),
-            capacity: self.capacity,
-        })
+        Ok(info)
     }
 
     fn read(&self) -> Result<Vec<u8>, SharedMemoryError> {
@@ -203,11 +470,122 @@ impl SharedMemoryRegion for LinuxSharedMemoryRegion {
     }
 
     unsafe fn data_ptr_mut(&mut self) -> *mut u8 {
-        // SAFETY: mmap is valid and HEADER_SIZE is within bounds
-        unsafe { self.mmap.as_mut_ptr().add(HEADER_SIZE) }
+        // SAFETY: mmap is valid and HEADER_SIZE is within bounds. Callers
+        // must not write through this pointer when `self.read_only` is set —
+        // the mapping itself is `PROT_READ`, so doing so would fault.
+        unsafe { (self.mmap.as_ptr() as *mut u8).add(HEADER_SIZE) }
+    }
+
+    /// Overrides the trait default to go through the same seqlock as
+    /// [`write`](SharedMemoryRegion::write): the default implementation
+    /// patches memory directly without bumping `self.handle`'s sequence
+    /// counter, which would let a concurrent `RegionHandle::read_consistent`
+    /// observe a torn window instead of retrying.
+    fn write_at(&mut self, version: u64, offset: usize, data: &[u8]) -> Result<SharedStateInfo, SharedMemoryError> {
+        if self.read_only {
+            return Err(SharedMemoryError::ReadOnly(self.name.clone()));
+        }
+        if offset.checked_add(data.len()).is_none_or(|end| end > self.capacity) {
+            return Err(SharedMemoryError::InvalidRange {
+                offset,
+                len: data.len(),
+                capacity: self.capacity,
+            });
+        }
+
+        let Mapping::Writable(mmap) = &mut self.mmap else {
+            unreachable!("read_only checked above")
+        };
+
+        self.handle.begin_write();
+
+        let (_, current_length) =
+            read_header(mmap, self.capacity).ok_or(SharedMemoryError::InvalidHeader)?;
+        let new_length = current_length.max(offset + data.len());
+
+        let data_offset = HEADER_SIZE + offset;
+        mmap[data_offset..data_offset + data.len()].copy_from_slice(data);
+        write_header_unchecked(mmap, version, new_length);
+
+        self.handle.end_write();
+        self.handle.dirty.lock().unwrap().mark_range(offset, data.len());
+
+        // See `write_durable`'s matching comment: the trailer needs to reach
+        // disk alongside the header so a crash can't leave it stuck odd.
+        mmap.flush_range(0, HEADER_SIZE + self.capacity + SEQ_SIZE)
+            .map_err(|e| SharedMemoryError::Io(e.to_string()))?;
+
+        Ok(SharedStateInfo {
+            name: self.name.clone(),
+            path: Some(self.path.clone()),
+            fd: Some(self.file.as_raw_fd()),
+            version,
+            length: new_length,
+            capacity: self.capacity,
+            sealed: false,
+        })
+    }
+
+    /// Serves precise dirty ranges from `self.handle`'s [`DirtyBitmap`] when
+    /// `last_version` matches the version the bitmap has been tracking
+    /// since; otherwise (a new reader, one that's fallen out of sync, or a
+    /// bitmap that's overflowed) reports `None` so the caller falls back to
+    /// a full read. Either way, the bitmap is reset to start tracking from
+    /// the region's current version, so only one incremental reader can be
+    /// caught up at a time — concurrent readers at different versions just
+    /// see more frequent full-read fallbacks, never incorrect data.
+    fn read_dirty_since(
+        &self,
+        last_version: u64,
+    ) -> Result<(u64, Option<Vec<(usize, Vec<u8>)>>), SharedMemoryError> {
+        let info = self.info()?;
+        let mut bitmap = self.handle.dirty.lock().unwrap();
+
+        if last_version != bitmap.since_version() {
+            bitmap.reset(info.version);
+            return Ok((info.version, None));
+        }
+
+        let ranges = match bitmap.dirty_ranges(self.capacity) {
+            Some(ranges) => ranges,
+            None => {
+                bitmap.reset(info.version);
+                return Ok((info.version, None));
+            }
+        };
+
+        let data = ranges
+            .into_iter()
+            .map(|(offset, len)| {
+                // SAFETY: `dirty_ranges` clamps every range to `self.capacity`.
+                let bytes =
+                    unsafe { std::slice::from_raw_parts(self.data_ptr().add(offset), len) }
+                        .to_vec();
+                (offset, bytes)
+            })
+            .collect();
+
+        bitmap.reset(info.version);
+        Ok((info.version, Some(data)))
     }
 }
 
+/// Everything a peer process needs to attach to a region without going
+/// through its own registry: the transferable descriptor plus the sizing
+/// info `import_region` would otherwise have no way to learn, since the
+/// peer's `REGISTRY` never heard of this buffer.
+///
+/// `fd` still has to cross the process boundary by some other means first —
+/// typically `fd_transfer::send_fd` over a `UnixStream` — this struct just
+/// carries the rest of what `import_region` needs alongside it.
+#[derive(Debug)]
+pub struct ExportToken {
+    pub name: String,
+    pub capacity: usize,
+    pub total_size: usize,
+    pub fd: std::os::fd::RawFd,
+}
+
 /// Factory for creating Linux memio regions.
 #[derive(Debug, Clone)]
 pub struct LinuxSharedMemoryFactory {
@@ -247,7 +625,7 @@ impl LinuxSharedMemoryFactory {
         capacity: usize,
         create: bool,
     ) -> Result<LinuxSharedMemoryRegion, SharedMemoryError> {
-        let file_len = HEADER_SIZE + capacity;
+        let file_len = HEADER_SIZE + capacity + SEQ_SIZE;
 
         let file = OpenOptions::new()
             .read(true)
@@ -286,11 +664,212 @@ impl LinuxSharedMemoryFactory {
             registry.insert(name.to_string(), path.clone());
         }
 
+        // The mapping's address is stable for the lifetime of the mmap
+        // (moving the `MmapMut` wrapper doesn't remap), so it's safe to
+        // hand clones of this raw pointer out via `RegionHandle`.
+        let initial_version = if create { 0 } else { read_version(&mmap).unwrap_or(0) };
+        let handle = Arc::new(RegionHandle {
+            ptr: mmap.as_mut_ptr(),
+            capacity,
+            dirty: Mutex::new(DirtyBitmap::new(capacity, initial_version)),
+        });
+
+        Ok(LinuxSharedMemoryRegion {
+            name: name.to_string(),
+            path,
+            file,
+            mmap: Mapping::Writable(mmap),
+            capacity,
+            handle,
+            read_only: false,
+        })
+    }
+
+    /// Opens an existing memio file with a `PROT_READ`-only mapping,
+    /// reopening the fd read-only first so there's no window where the
+    /// process holds a writable view of a region it's about to treat as
+    /// read-only.
+    fn open_readonly_impl(
+        &self,
+        name: &str,
+        path: PathBuf,
+        capacity: usize,
+    ) -> Result<LinuxSharedMemoryRegion, SharedMemoryError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(&path)
+            .map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?;
+
+        let mmap = unsafe { Mmap::map(&file).map_err(|_| SharedMemoryError::MmapFailed)? };
+
+        if !validate_magic(&mmap) {
+            return Err(SharedMemoryError::InvalidHeader);
+        }
+
+        let initial_version = read_version(&mmap).unwrap_or(0);
+        let handle = Arc::new(RegionHandle {
+            // SAFETY: the pointer is only ever read through `RegionHandle`;
+            // the mapping itself rejects writes at the kernel level.
+            ptr: mmap.as_ptr() as *mut u8,
+            capacity,
+            dirty: Mutex::new(DirtyBitmap::new(capacity, initial_version)),
+        });
+
         Ok(LinuxSharedMemoryRegion {
             name: name.to_string(),
             path,
-            mmap,
+            file,
+            mmap: Mapping::ReadOnly(mmap),
             capacity,
+            handle,
+            read_only: true,
+        })
+    }
+
+    /// Creates or re-opens a persistent memio region backed by a regular
+    /// file at `path`, instead of a disposable file under `/dev/shm`. If
+    /// `path` already exists, its last committed header and payload are
+    /// preserved rather than zeroed (capacity is derived from the file's
+    /// size, the same way `open` does it), so a restart picks up exactly
+    /// where the previous process left off; otherwise a fresh region of
+    /// `capacity` bytes is created.
+    pub fn create_persistent(
+        &self,
+        name: &str,
+        path: PathBuf,
+        capacity: usize,
+    ) -> Result<LinuxSharedMemoryRegion, SharedMemoryError> {
+        if path.exists() {
+            let metadata =
+                fs::metadata(&path).map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?;
+            let file_len = metadata.len() as usize;
+
+            if file_len < HEADER_SIZE + SEQ_SIZE {
+                return Err(SharedMemoryError::InvalidHeader);
+            }
+
+            let existing_capacity = file_len - HEADER_SIZE - SEQ_SIZE;
+            self.open_or_create(name, path, existing_capacity, false)
+        } else {
+            if capacity == 0 {
+                return Err(SharedMemoryError::InvalidCapacity);
+            }
+            self.open_or_create(name, path, capacity, true)
+        }
+    }
+
+    /// Builds an [`ExportToken`] for a region this process created, by
+    /// reopening its backing file from `REGISTRY` and duplicating a fresh fd
+    /// to it — so the caller can hand the token's `fd` to `fd_transfer::send_fd`
+    /// and ship `capacity`/`total_size` alongside it by whatever channel it's
+    /// already using to coordinate with the peer (the fd itself carries no
+    /// sizing information once detached from this process's mapping).
+    pub fn export_region(&self, name: &str) -> Result<ExportToken, SharedMemoryError> {
+        let path = {
+            let registry = REGISTRY.lock().unwrap();
+            registry.get(name).cloned()
+        }
+        .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&path)
+            .map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?;
+        let total_size = file
+            .metadata()
+            .map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?
+            .len() as usize;
+
+        if total_size < HEADER_SIZE + SEQ_SIZE {
+            return Err(SharedMemoryError::InvalidHeader);
+        }
+
+        let fd = unsafe { libc::dup(file.as_raw_fd()) };
+        if fd < 0 {
+            return Err(SharedMemoryError::Io(io::Error::last_os_error().to_string()));
+        }
+
+        Ok(ExportToken {
+            name: name.to_string(),
+            capacity: total_size - HEADER_SIZE - SEQ_SIZE,
+            total_size,
+            fd,
+        })
+    }
+
+    /// Attaches to a region from an [`ExportToken`] received from another
+    /// process (with `token.fd` itself already transferred separately, e.g.
+    /// via `fd_transfer::recv_fd`). Thin wrapper over [`Self::import_fd`] —
+    /// this is just the token-based entry point `export_region` pairs with.
+    pub fn import_region(&self, token: ExportToken) -> Result<LinuxSharedMemoryRegion, SharedMemoryError> {
+        self.import_fd(&token.name, token.fd, token.capacity)
+    }
+
+    /// Maps an externally-supplied descriptor (typically received over
+    /// `fd_transfer::recv_fd` from another process) into a region, without
+    /// creating or truncating any storage of its own — the fd already
+    /// points at an initialized, correctly-sized memio file.
+    ///
+    /// `capacity` crosses a trust boundary here: it may have been read off
+    /// the wire from a peer (see `RegistryClient::open`) rather than
+    /// produced by this process's own bookkeeping. Trusting it unchecked
+    /// would let a lying or simply wrong peer make every later seqlock
+    /// read/write (built from `RegionHandle::mapped_len()`) index past the
+    /// fd's actual mapping, so it's validated against `fstat` before
+    /// anything is mapped.
+    pub fn import_fd(
+        &self,
+        name: &str,
+        fd: std::os::fd::RawFd,
+        capacity: usize,
+    ) -> Result<LinuxSharedMemoryRegion, SharedMemoryError> {
+        use std::os::fd::FromRawFd;
+
+        // SAFETY: the caller hands over ownership of `fd` (e.g. one just
+        // received via `recvmsg`'s `SCM_RIGHTS` control message), so it's
+        // valid for us to take it as a `File`.
+        let file = unsafe { fs::File::from_raw_fd(fd) };
+
+        let file_len = file
+            .metadata()
+            .map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?
+            .len() as usize;
+        if file_len < HEADER_SIZE + SEQ_SIZE {
+            return Err(SharedMemoryError::InvalidHeader);
+        }
+        let real_capacity = file_len - HEADER_SIZE - SEQ_SIZE;
+        if real_capacity != capacity {
+            return Err(SharedMemoryError::Protocol(format!(
+                "import_fd: fd is sized for capacity {real_capacity} bytes, caller claimed {capacity}"
+            )));
+        }
+
+        let mut mmap =
+            unsafe { MmapMut::map_mut(&file).map_err(|_| SharedMemoryError::MmapFailed)? };
+
+        if !validate_magic(&mmap) {
+            return Err(SharedMemoryError::InvalidHeader);
+        }
+
+        let initial_version = read_version(&mmap).unwrap_or(0);
+        let handle = Arc::new(RegionHandle {
+            ptr: mmap.as_mut_ptr(),
+            capacity: real_capacity,
+            dirty: Mutex::new(DirtyBitmap::new(real_capacity, initial_version)),
+        });
+
+        Ok(LinuxSharedMemoryRegion {
+            name: name.to_string(),
+            // No path of our own — this region's storage is owned by
+            // whichever process exported the fd, so there's nothing for
+            // this side's `Drop` impl to unlink.
+            path: PathBuf::new(),
+            file,
+            mmap: Mapping::Writable(mmap),
+            capacity: real_capacity,
+            handle,
+            read_only: false,
         })
     }
 }
@@ -327,14 +906,33 @@ impl SharedMemoryFactory for LinuxSharedMemoryFactory {
             fs::metadata(&path).map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?;
         let file_len = metadata.len() as usize;
 
-        if file_len < HEADER_SIZE {
+        if file_len < HEADER_SIZE + SEQ_SIZE {
             return Err(SharedMemoryError::InvalidHeader);
         }
 
-        let capacity = file_len - HEADER_SIZE;
+        let capacity = file_len - HEADER_SIZE - SEQ_SIZE;
         self.open_or_create(name, path, capacity, false)
     }
 
+    fn open_readonly(&self, name: &str) -> Result<Self::Region, SharedMemoryError> {
+        let path = {
+            let registry = REGISTRY.lock().unwrap();
+            registry.get(name).cloned()
+        };
+        let path = path.ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+
+        let metadata =
+            fs::metadata(&path).map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?;
+        let file_len = metadata.len() as usize;
+
+        if file_len < HEADER_SIZE + SEQ_SIZE {
+            return Err(SharedMemoryError::InvalidHeader);
+        }
+
+        let capacity = file_len - HEADER_SIZE - SEQ_SIZE;
+        self.open_readonly_impl(name, path, capacity)
+    }
+
     fn list(&self) -> Vec<String> {
         let registry = REGISTRY.lock().unwrap();
         registry.keys().cloned().collect()
@@ -0,0 +1,464 @@
+//! Anonymous `memfd_create`-backed memio region implementation.
+//!
+//! Unlike [`crate::linux::LinuxSharedMemoryFactory`], which materializes regions as
+//! named files under `/dev/shm`, this backend creates an anonymous, sealed tmpfs
+//! file descriptor that is never visible in the filesystem. The region is reclaimed
+//! automatically by the kernel once the last fd referencing it closes, so there is
+//! no orphan-file cleanup to run at startup.
+
+use std::ffi::CString;
+use std::fs::File;
+use std::os::fd::{AsRawFd, FromRawFd, RawFd};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use memmap2::MmapMut;
+use once_cell::sync::Lazy;
+
+use memio_core::{
+    SHARED_STATE_HEADER_SIZE, SharedMemoryError, SharedMemoryFactory, SharedMemoryRegion,
+    SharedStateInfo, read_header, write_header_unchecked,
+};
+
+const HEADER_SIZE: usize = SHARED_STATE_HEADER_SIZE;
+
+/// Counter for generating unique memfd names (names are cosmetic only; they never
+/// appear in the filesystem).
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Registry tracking raw fds for regions created in this process, so `open` can
+/// hand out an independent mapping of the same memfd.
+static REGISTRY: Lazy<Mutex<std::collections::HashMap<String, RawFd>>> =
+    Lazy::new(|| Mutex::new(std::collections::HashMap::new()));
+
+/// Calls `memfd_create(2)` with `MFD_CLOEXEC | MFD_ALLOW_SEALING`.
+fn memfd_create(name: &str) -> Result<RawFd, SharedMemoryError> {
+    let cname = CString::new(name)
+        .map_err(|e| SharedMemoryError::CreateFailed(format!("invalid memfd name: {e}")))?;
+
+    let fd = unsafe {
+        libc::memfd_create(
+            cname.as_ptr(),
+            libc::MFD_CLOEXEC | libc::MFD_ALLOW_SEALING,
+        )
+    };
+
+    if fd < 0 {
+        return Err(SharedMemoryError::CreateFailed(format!(
+            "memfd_create failed: {}",
+            std::io::Error::last_os_error()
+        )));
+    }
+
+    Ok(fd)
+}
+
+/// Applies `F_SEAL_SHRINK | F_SEAL_GROW` so the region's size is frozen.
+///
+/// Must be called *after* the final `ftruncate`, otherwise the shrink seal would
+/// reject the truncate that establishes the region's capacity.
+///
+/// Deliberately doesn't also add `F_SEAL_SEAL` here: that would lock the
+/// seal set itself, and [`MemfdSharedMemoryFactory::create_sealed`] needs
+/// to add `F_SEAL_WRITE` later, once the payload has actually been
+/// written.
+fn seal_size(fd: RawFd) -> Result<(), SharedMemoryError> {
+    let seals = libc::F_SEAL_SHRINK | libc::F_SEAL_GROW;
+    let ret = unsafe { libc::fcntl(fd, libc::F_ADD_SEALS, seals) };
+    if ret < 0 {
+        return Err(SharedMemoryError::CreateFailed(format!(
+            "fcntl(F_ADD_SEALS) failed: {}",
+            std::io::Error::last_os_error()
+        )));
+    }
+    Ok(())
+}
+
+/// Additionally applies `F_SEAL_WRITE`, freezing the region's bytes for good.
+///
+/// Must be called *after* the payload has been written: once applied, the
+/// kernel itself refuses any further write to the memfd, through any fd
+/// referencing it, not just this process's in-memory `sealed` guard.
+fn seal_write(fd: RawFd) -> Result<(), SharedMemoryError> {
+    let seals = libc::F_SEAL_WRITE | libc::F_SEAL_SHRINK | libc::F_SEAL_GROW;
+    let ret = unsafe { libc::fcntl(fd, libc::F_ADD_SEALS, seals) };
+    if ret < 0 {
+        return Err(SharedMemoryError::CreateFailed(format!(
+            "fcntl(F_ADD_SEALS) failed: {}",
+            std::io::Error::last_os_error()
+        )));
+    }
+    Ok(())
+}
+
+/// A memio region backed by an anonymous, sealed `memfd_create` descriptor.
+#[derive(Debug)]
+pub struct MemfdSharedMemoryRegion {
+    name: String,
+    mmap: MmapMut,
+    capacity: usize,
+    fd: RawFd,
+    /// `true` once `F_SEAL_WRITE` has been applied via
+    /// [`MemfdSharedMemoryFactory::create_sealed`] — further writes are
+    /// rejected both by this guard and, independently, by the kernel.
+    sealed: bool,
+}
+
+// SAFETY: the fd and mmap are only touched through the synchronized trait methods.
+unsafe impl Send for MemfdSharedMemoryRegion {}
+unsafe impl Sync for MemfdSharedMemoryRegion {}
+
+impl MemfdSharedMemoryRegion {
+    /// Returns the name of this region.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the raw memfd descriptor backing this region.
+    ///
+    /// This is the handle to pass to a peer process (e.g. over a Unix socket with
+    /// `SCM_RIGHTS`); the region stays alive as long as any fd referencing it is open.
+    pub fn fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+impl Drop for MemfdSharedMemoryRegion {
+    fn drop(&mut self) {
+        // No filesystem entry to unlink: the kernel reclaims the memfd once the
+        // last fd referencing it (ours, here) closes.
+        if let Ok(mut registry) = REGISTRY.lock() {
+            registry.remove(&self.name);
+        }
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+impl SharedMemoryRegion for MemfdSharedMemoryRegion {
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn info(&self) -> Result<SharedStateInfo, SharedMemoryError> {
+        let (version, length) =
+            read_header(&self.mmap, self.capacity).ok_or(SharedMemoryError::InvalidHeader)?;
+
+        Ok(SharedStateInfo {
+            name: self.name.clone(),
+            path: None,
+            fd: Some(self.fd),
+            version,
+            length,
+            capacity: self.capacity,
+            sealed: self.sealed,
+        })
+    }
+
+    fn write(&mut self, version: u64, data: &[u8]) -> Result<SharedStateInfo, SharedMemoryError> {
+        if self.sealed {
+            return Err(SharedMemoryError::Sealed(self.name.clone()));
+        }
+
+        if data.len() > self.capacity {
+            return Err(SharedMemoryError::DataTooLarge {
+                data_len: data.len(),
+                capacity: self.capacity,
+            });
+        }
+
+        let data_offset = HEADER_SIZE;
+        self.mmap[data_offset..data_offset + data.len()].copy_from_slice(data);
+        write_header_unchecked(&mut self.mmap, version, data.len());
+
+        self.mmap
+            .flush()
+            .map_err(|e| SharedMemoryError::Io(e.to_string()))?;
+
+        Ok(SharedStateInfo {
+            name: self.name.clone(),
+            path: None,
+            fd: Some(self.fd),
+            version,
+            length: data.len(),
+            capacity: self.capacity,
+            sealed: self.sealed,
+        })
+    }
+
+    fn read(&self) -> Result<Vec<u8>, SharedMemoryError> {
+        let (_, length) =
+            read_header(&self.mmap, self.capacity).ok_or(SharedMemoryError::InvalidHeader)?;
+
+        let data_offset = HEADER_SIZE;
+        let mut data = vec![0u8; length];
+        data.copy_from_slice(&self.mmap[data_offset..data_offset + length]);
+
+        Ok(data)
+    }
+
+    unsafe fn data_ptr(&self) -> *const u8 {
+        // SAFETY: mmap is valid and HEADER_SIZE is within bounds
+        unsafe { self.mmap.as_ptr().add(HEADER_SIZE) }
+    }
+
+    unsafe fn data_ptr_mut(&mut self) -> *mut u8 {
+        // SAFETY: mmap is valid and HEADER_SIZE is within bounds
+        unsafe { self.mmap.as_mut_ptr().add(HEADER_SIZE) }
+    }
+
+    /// Overrides the default `write_at` purely to reject writes against a
+    /// sealed region before touching the mapping; the range-check/header
+    /// logic mirrors the default implementation in
+    /// `memio_core::SharedMemoryRegion`, adapted to this file's slice-based
+    /// access pattern rather than raw pointers.
+    fn write_at(
+        &mut self,
+        version: u64,
+        offset: usize,
+        data: &[u8],
+    ) -> Result<SharedStateInfo, SharedMemoryError> {
+        if self.sealed {
+            return Err(SharedMemoryError::Sealed(self.name.clone()));
+        }
+
+        let capacity = self.capacity;
+        if offset.checked_add(data.len()).is_none_or(|end| end > capacity) {
+            return Err(SharedMemoryError::InvalidRange { offset, len: data.len(), capacity });
+        }
+
+        let (_, length) =
+            read_header(&self.mmap, capacity).ok_or(SharedMemoryError::InvalidHeader)?;
+        let new_length = length.max(offset + data.len());
+
+        let data_offset = HEADER_SIZE + offset;
+        self.mmap[data_offset..data_offset + data.len()].copy_from_slice(data);
+        write_header_unchecked(&mut self.mmap, version, new_length);
+
+        self.mmap
+            .flush()
+            .map_err(|e| SharedMemoryError::Io(e.to_string()))?;
+
+        Ok(SharedStateInfo {
+            name: self.name.clone(),
+            path: None,
+            fd: Some(self.fd),
+            version,
+            length: new_length,
+            capacity,
+            sealed: self.sealed,
+        })
+    }
+}
+
+/// Factory for creating anonymous `memfd_create`-backed memio regions.
+#[derive(Debug, Clone, Default)]
+pub struct MemfdSharedMemoryFactory;
+
+impl MemfdSharedMemoryFactory {
+    /// Creates a new factory.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Creates a region, writes `data` into it once, then permanently seals
+    /// it against further writes via `F_SEAL_WRITE` — the kernel itself
+    /// refuses any subsequent write to the memfd, through any fd referencing
+    /// it, not just this process's in-memory `sealed` guard. The returned
+    /// region's `info().sealed` is `true`, and any peer that later `open`s
+    /// the same name sees `sealed: true` too (detected via `F_GET_SEALS`).
+    pub fn create_sealed(
+        &self,
+        name: &str,
+        data: &[u8],
+    ) -> Result<MemfdSharedMemoryRegion, SharedMemoryError> {
+        let mut region = self.create(name, data.len().max(1))?;
+        region.write(1, data)?;
+        // `F_SEAL_WRITE` fails with `EBUSY` while a shared writable mapping
+        // exists, so swap ours for a private copy-on-write one first.
+        let file = std::mem::ManuallyDrop::new(unsafe { File::from_raw_fd(region.fd) });
+        region.mmap = unsafe { memmap2::MmapOptions::new().map_copy(&*file) }
+            .map_err(|_| SharedMemoryError::MmapFailed)?;
+        seal_write(region.fd)?;
+        region.sealed = true;
+        Ok(region)
+    }
+}
+
+impl SharedMemoryFactory for MemfdSharedMemoryFactory {
+    type Region = MemfdSharedMemoryRegion;
+
+    fn create(&self, name: &str, capacity: usize) -> Result<Self::Region, SharedMemoryError> {
+        if capacity == 0 {
+            return Err(SharedMemoryError::InvalidCapacity);
+        }
+
+        let file_len = HEADER_SIZE + capacity;
+        let nonce = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let memfd_name = format!("memio_{}_{}_{}", name, std::process::id(), nonce);
+
+        let fd = memfd_create(&memfd_name)?;
+        let file = unsafe { File::from_raw_fd(fd) };
+        file.set_len(file_len as u64)
+            .map_err(|e| SharedMemoryError::CreateFailed(e.to_string()))?;
+
+        // Seal the size only after the ftruncate that establishes capacity;
+        // F_SEAL_SHRINK would otherwise reject that truncate.
+        seal_size(file.as_raw_fd())?;
+
+        let mut mmap =
+            unsafe { MmapMut::map_mut(&file).map_err(|_| SharedMemoryError::MmapFailed)? };
+        write_header_unchecked(&mut mmap, 0, 0);
+
+        let raw_fd = file.as_raw_fd();
+        // Keep the fd alive for the lifetime of the region; `File`'s Drop would
+        // otherwise close it when `file` goes out of scope.
+        std::mem::forget(file);
+
+        {
+            let mut registry = REGISTRY.lock().unwrap();
+            registry.insert(name.to_string(), raw_fd);
+        }
+
+        Ok(MemfdSharedMemoryRegion {
+            name: name.to_string(),
+            mmap,
+            capacity,
+            fd: raw_fd,
+            sealed: false,
+        })
+    }
+
+    fn open(&self, name: &str) -> Result<Self::Region, SharedMemoryError> {
+        let fd = {
+            let registry = REGISTRY.lock().unwrap();
+            registry
+                .get(name)
+                .copied()
+                .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?
+        };
+
+        // Dup so the new region owns an independent fd/lifetime from the original.
+        let dup_fd = unsafe { libc::fcntl(fd, libc::F_DUPFD_CLOEXEC, 0) };
+        if dup_fd < 0 {
+            return Err(SharedMemoryError::OpenFailed(format!(
+                "dup of memfd failed: {}",
+                std::io::Error::last_os_error()
+            )));
+        }
+
+        let file = unsafe { File::from_raw_fd(dup_fd) };
+        let file_len = file
+            .metadata()
+            .map_err(|e| SharedMemoryError::OpenFailed(e.to_string()))?
+            .len() as usize;
+
+        if file_len < HEADER_SIZE {
+            return Err(SharedMemoryError::InvalidHeader);
+        }
+        let capacity = file_len - HEADER_SIZE;
+
+        // Ask the kernel rather than trusting local state: the original
+        // region (possibly in another process) may have been sealed via
+        // `create_sealed` after this registry entry was made.
+        let seals = unsafe { libc::fcntl(file.as_raw_fd(), libc::F_GET_SEALS) };
+        let sealed = seals >= 0 && seals & libc::F_SEAL_WRITE != 0;
+
+        // A write-sealed memfd refuses a shared writable mapping, so map it
+        // copy-on-write instead; the `sealed` guard rejects writes to it.
+        let mmap = if sealed {
+            unsafe { memmap2::MmapOptions::new().map_copy(&file) }
+        } else {
+            unsafe { MmapMut::map_mut(&file) }
+        }
+        .map_err(|_| SharedMemoryError::MmapFailed)?;
+        let raw_fd = file.as_raw_fd();
+        std::mem::forget(file);
+
+        Ok(MemfdSharedMemoryRegion {
+            name: name.to_string(),
+            mmap,
+            capacity,
+            fd: raw_fd,
+            sealed,
+        })
+    }
+
+    fn list(&self) -> Vec<String> {
+        let registry = REGISTRY.lock().unwrap();
+        registry.keys().cloned().collect()
+    }
+
+    fn exists(&self, name: &str) -> bool {
+        let registry = REGISTRY.lock().unwrap();
+        registry.contains_key(name)
+    }
+
+    fn remove(&self, name: &str) -> Result<(), SharedMemoryError> {
+        let fd = {
+            let mut registry = REGISTRY.lock().unwrap();
+            registry.remove(name)
+        };
+
+        if let Some(fd) = fd {
+            unsafe {
+                libc::close(fd);
+            }
+            Ok(())
+        } else {
+            Err(SharedMemoryError::NotFound(name.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_and_write() {
+        let factory = MemfdSharedMemoryFactory::new();
+        let mut region = factory.create("memfd_test1", 1024).unwrap();
+
+        let info = region.write(1, b"hello world").unwrap();
+        assert_eq!(info.version, 1);
+        assert_eq!(info.length, 11);
+        assert!(info.fd.is_some());
+
+        let data = region.read().unwrap();
+        assert_eq!(data, b"hello world");
+
+        factory.remove("memfd_test1").unwrap();
+    }
+
+    #[test]
+    fn test_capacity_exceeded() {
+        let factory = MemfdSharedMemoryFactory::new();
+        let mut region = factory.create("memfd_test2", 10).unwrap();
+
+        let result = region.write(1, b"this is too long");
+        assert!(matches!(
+            result,
+            Err(SharedMemoryError::DataTooLarge { .. })
+        ));
+
+        factory.remove("memfd_test2").unwrap();
+    }
+
+    #[test]
+    fn test_create_sealed_rejects_write() {
+        let factory = MemfdSharedMemoryFactory::new();
+        let region = factory.create_sealed("memfd_test3", b"frozen").unwrap();
+
+        let info = region.info().unwrap();
+        assert!(info.sealed);
+        assert_eq!(region.read().unwrap(), b"frozen");
+
+        let mut region = region;
+        let result = region.write(2, b"nope");
+        assert!(matches!(result, Err(SharedMemoryError::Sealed(_))));
+
+        factory.remove("memfd_test3").unwrap();
+    }
+}
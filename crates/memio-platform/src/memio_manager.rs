@@ -2,17 +2,23 @@
 //!
 //! Provides a unified interface for creating and managing memio buffers.
 
-#[cfg(target_os = "android")]
+#[cfg(any(target_os = "linux", target_os = "android"))]
 use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 #[cfg(target_os = "linux")]
-use memio_core::SharedMemoryRegion;
+use memio_core::{MemioChannel, SharedMemoryFactory, SharedMemoryRegion};
 use memio_core::{SharedMemoryError, SharedStateInfo};
+use rkyv::Archive;
+use rkyv::rancor::Error as RancorError;
 
 #[cfg(target_os = "linux")]
-use crate::linux::LinuxSharedMemoryFactory;
+use crate::linux::{LinuxSharedMemoryFactory, LinuxSharedMemoryRegion, RegionHandle};
+#[cfg(target_os = "linux")]
+use crate::linux_memfd::{MemfdSharedMemoryFactory, MemfdSharedMemoryRegion};
 #[cfg(target_os = "linux")]
 use crate::registry::SharedRegistry;
 
@@ -25,6 +31,36 @@ pub struct MemioManager {
     #[cfg(target_os = "linux")]
     registry: Mutex<SharedRegistry<LinuxSharedMemoryFactory>>,
 
+    /// Cached lock-free read handles, keyed by buffer name. Populated once
+    /// by `create_buffer`, `create_persistent_buffer` or
+    /// `import_buffer_from_fd` so `read`/`version` never take `registry`'s
+    /// mutex: a writer holding it to serialize against other writers no
+    /// longer stalls a concurrent reader.
+    #[cfg(target_os = "linux")]
+    handles: Mutex<HashMap<String, Arc<RegionHandle>>>,
+
+    /// Names of buffers created via `create_persistent_buffer`. `write`
+    /// consults this to pick the crash-consistent write ordering
+    /// (`write_durable`) instead of the ordinary one, which doesn't matter
+    /// for volatile `/dev/shm` buffers.
+    #[cfg(target_os = "linux")]
+    persistent: Mutex<HashSet<String>>,
+
+    /// Ring-mode buffers created via `create_ring_buffer`, each a standalone
+    /// region (not tracked in `registry`) wrapped in a `MemioChannel` so
+    /// producer/consumer frames go through `ring_push`/`ring_pop` instead of
+    /// `write`/`read` overwriting a single slot.
+    #[cfg(target_os = "linux")]
+    rings: Mutex<HashMap<String, MemioChannel<LinuxSharedMemoryRegion>>>,
+
+    /// Sealed, publish-once buffers created via `create_sealed_buffer`, each
+    /// backed by an anonymous `memfd_create` region (see `linux_memfd`)
+    /// rather than the registry's named `/dev/shm` files — tracked
+    /// separately since they're a different concrete region type and never
+    /// go through `write`/`write_at`.
+    #[cfg(target_os = "linux")]
+    sealed: Mutex<HashMap<String, MemfdSharedMemoryRegion>>,
+
     #[cfg(target_os = "android")]
     buffers: Mutex<HashMap<String, BufferInfo>>,
 
@@ -57,6 +93,49 @@ pub struct ReadResult {
     pub version: u64,
 }
 
+/// A validated, zero-copy view of a buffer's contents as an archived `T`,
+/// returned by `MemioManager::access_model`.
+///
+/// Holds the bytes the archive was validated against alongside a raw
+/// pointer into them, so `Deref` can hand back a reference without
+/// re-validating (and without a lifetime tying it to the manager).
+pub struct MemioRef<T: Archive> {
+    bytes: Vec<u8>,
+    archived: *const T::Archived,
+}
+
+impl<T: Archive> MemioRef<T>
+where
+    T::Archived: for<'a> bytecheck::CheckBytes<rkyv::api::high::HighValidator<'a, RancorError>>,
+{
+    fn new(bytes: Vec<u8>) -> Result<Self, SharedMemoryError> {
+        let archived = rkyv::access::<T::Archived, RancorError>(&bytes)
+            .map_err(|e| SharedMemoryError::Deserialization(e.to_string()))?
+            as *const T::Archived;
+        Ok(Self { bytes, archived })
+    }
+}
+
+impl<T: Archive> std::ops::Deref for MemioRef<T> {
+    type Target = T::Archived;
+
+    fn deref(&self) -> &T::Archived {
+        // SAFETY: `archived` was produced by `rkyv::access` validating
+        // `self.bytes` in `new`, and `bytes` is never touched again after
+        // that — its heap allocation (and thus this pointer) stays valid
+        // for as long as `self` is alive, regardless of where `self` moves.
+        unsafe { &*self.archived }
+    }
+}
+
+impl<T: Archive> std::fmt::Debug for MemioRef<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemioRef")
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
 impl MemioManager {
     /// Creates a new MemioManager for the current platform.
     #[cfg(target_os = "linux")]
@@ -67,6 +146,10 @@ impl MemioManager {
             .map_err(|e| SharedMemoryError::CreateFailed(e.to_string()))?;
         Ok(Self {
             registry: Mutex::new(registry),
+            handles: Mutex::new(HashMap::new()),
+            persistent: Mutex::new(HashSet::new()),
+            rings: Mutex::new(HashMap::new()),
+            sealed: Mutex::new(HashMap::new()),
         })
     }
 
@@ -87,9 +170,161 @@ impl MemioManager {
     pub fn create_buffer(&self, name: &str, capacity: usize) -> Result<(), SharedMemoryError> {
         let mut registry = self.registry.lock()?;
         registry.create_buffer(name.to_string(), capacity)?;
+
+        if let Some(region) = registry.get(name) {
+            let mut handles = self.handles.lock()?;
+            handles.insert(name.to_string(), region.handle());
+        }
+
+        Ok(())
+    }
+
+    /// Creates or re-opens a file-backed persistent buffer at `path`. Unlike
+    /// `create_buffer`'s disposable `/dev/shm` storage, an existing file at
+    /// `path` is preserved as-is (header and payload included), so `read`/
+    /// `info` reflect the last version committed before a restart rather
+    /// than starting over. Writes to a buffer created this way go through
+    /// the crash-consistent ordering in `write` instead of the ordinary one.
+    #[cfg(target_os = "linux")]
+    pub fn create_persistent_buffer(
+        &self,
+        name: &str,
+        path: impl Into<std::path::PathBuf>,
+        capacity: usize,
+    ) -> Result<(), SharedMemoryError> {
+        let mut registry = self.registry.lock()?;
+        registry.create_persistent_buffer(name.to_string(), path.into(), capacity)?;
+
+        if let Some(region) = registry.get(name) {
+            let mut handles = self.handles.lock()?;
+            handles.insert(name.to_string(), region.handle());
+        }
+
+        let mut persistent = self.persistent.lock()?;
+        persistent.insert(name.to_string());
+
+        Ok(())
+    }
+
+    /// Creates a streaming ring buffer: a lock-free single-producer/single-
+    /// consumer queue of length-prefixed frames, for producers that emit a
+    /// stream of messages instead of a single value a slower consumer might
+    /// otherwise miss between polls. Drain it with `ring_pop` — `read`/
+    /// `write` don't apply to a buffer created this way.
+    #[cfg(target_os = "linux")]
+    pub fn create_ring_buffer(&self, name: &str, capacity: usize) -> Result<(), SharedMemoryError> {
+        let mut rings = self.rings.lock()?;
+        if rings.contains_key(name) {
+            return Err(SharedMemoryError::CreateFailed(format!(
+                "ring buffer '{}' already exists",
+                name
+            )));
+        }
+
+        let region = LinuxSharedMemoryFactory::new().create(name, capacity)?;
+        let channel = MemioChannel::new(region)?;
+        rings.insert(name.to_string(), channel);
+
         Ok(())
     }
 
+    /// Enqueues `data` as a frame on a ring buffer created via
+    /// `create_ring_buffer`, notifying any blocked reader.
+    #[cfg(target_os = "linux")]
+    pub fn ring_push(&self, name: &str, data: &[u8]) -> Result<(), SharedMemoryError> {
+        let mut rings = self.rings.lock()?;
+        let channel = rings
+            .get_mut(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+        channel.push(data)
+    }
+
+    /// Dequeues the next frame from a ring buffer without blocking, or
+    /// `None` if it's empty.
+    #[cfg(target_os = "linux")]
+    pub fn ring_pop(&self, name: &str) -> Result<Option<Vec<u8>>, SharedMemoryError> {
+        let mut rings = self.rings.lock()?;
+        let channel = rings
+            .get_mut(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+        channel.pop()
+    }
+
+    /// Publishes an immutable buffer: writes `data` once into a fresh
+    /// anonymous `memfd_create` region, then applies `F_SEAL_WRITE` (via
+    /// [`MemfdSharedMemoryFactory::create_sealed`]) so neither this process
+    /// nor any importer can mutate it afterward. `info_sealed(name)` on a
+    /// buffer created this way always reports `sealed: true`.
+    #[cfg(target_os = "linux")]
+    pub fn create_sealed_buffer(&self, name: &str, data: &[u8]) -> Result<(), SharedMemoryError> {
+        let mut sealed = self.sealed.lock()?;
+        if sealed.contains_key(name) {
+            return Err(SharedMemoryError::CreateFailed(format!(
+                "sealed buffer '{}' already exists",
+                name
+            )));
+        }
+
+        let region = MemfdSharedMemoryFactory::new().create_sealed(name, data)?;
+        sealed.insert(name.to_string(), region);
+        Ok(())
+    }
+
+    /// Reads a buffer created via `create_sealed_buffer`.
+    #[cfg(target_os = "linux")]
+    pub fn read_sealed(&self, name: &str) -> Result<ReadResult, SharedMemoryError> {
+        let sealed = self.sealed.lock()?;
+        let region = sealed
+            .get(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+
+        Ok(ReadResult {
+            data: region.read()?,
+            version: region.info()?.version,
+        })
+    }
+
+    /// Returns metadata (including `sealed: true`) for a buffer created via
+    /// `create_sealed_buffer`.
+    #[cfg(target_os = "linux")]
+    pub fn info_sealed(&self, name: &str) -> Result<SharedStateInfo, SharedMemoryError> {
+        let sealed = self.sealed.lock()?;
+        let region = sealed
+            .get(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+        region.info()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn create_sealed_buffer(&self, _name: &str, _data: &[u8]) -> Result<(), SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn read_sealed(&self, _name: &str) -> Result<ReadResult, SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn info_sealed(&self, _name: &str) -> Result<SharedStateInfo, SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn create_ring_buffer(&self, _name: &str, _capacity: usize) -> Result<(), SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn ring_push(&self, _name: &str, _data: &[u8]) -> Result<(), SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn ring_pop(&self, _name: &str) -> Result<Option<Vec<u8>>, SharedMemoryError> {
+        Err(SharedMemoryError::PlatformNotSupported)
+    }
+
     #[cfg(target_os = "android")]
     pub fn create_buffer(&self, name: &str, capacity: usize) -> Result<(), SharedMemoryError> {
         android::create_shared_region(name, capacity)?;
@@ -104,15 +339,27 @@ impl MemioManager {
     }
 
     /// Writes data to a memio buffer with versioning.
+    ///
+    /// Buffers created via `create_persistent_buffer` are written in
+    /// crash-consistent order (payload and length, then version, then an
+    /// `msync` of the whole mapping) instead of the ordinary order, so a
+    /// crash can never expose a newer version pointing at stale bytes.
     #[cfg(target_os = "linux")]
     pub fn write(&self, name: &str, version: u64, data: &[u8]) -> Result<WriteResult, SharedMemoryError> {
+        let is_persistent = self.persistent.lock()?.contains(name);
+
         let mut registry = self.registry.lock()?;
 
         let region = registry
             .get_mut(name)
             .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
 
-        let info = region.write(version, data)?;
+        let info = if is_persistent {
+            region.write_durable(version, data)?
+        } else {
+            region.write(version, data)?
+        };
+        registry.notify_fd(name);
 
         Ok(WriteResult {
             version: info.version,
@@ -135,24 +382,71 @@ impl MemioManager {
         Err(SharedMemoryError::PlatformNotSupported)
     }
 
-    /// Reads data from a memio buffer.
+    /// Reads a byte window from a buffer without copying the rest of the
+    /// payload. See [`SharedMemoryRegion::read_at`].
     #[cfg(target_os = "linux")]
-    pub fn read(&self, name: &str) -> Result<ReadResult, SharedMemoryError> {
+    pub fn read_at(&self, name: &str, offset: usize, len: usize) -> Result<Vec<u8>, SharedMemoryError> {
         let registry = self.registry.lock()?;
-
         let region = registry
             .get(name)
             .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+        region.read_at(offset, len)
+    }
 
-        let info = region.info()?;
-        let data = region.read()?;
+    /// Writes a byte window into a buffer and bumps its version, without
+    /// copying the rest of the payload. See [`SharedMemoryRegion::write_at`].
+    #[cfg(target_os = "linux")]
+    pub fn write_at(
+        &self,
+        name: &str,
+        version: u64,
+        offset: usize,
+        data: &[u8],
+    ) -> Result<WriteResult, SharedMemoryError> {
+        let mut registry = self.registry.lock()?;
+        let region = registry
+            .get_mut(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
 
-        Ok(ReadResult {
-            data,
+        let info = region.write_at(version, offset, data)?;
+        registry.notify_fd(name);
+
+        Ok(WriteResult {
             version: info.version,
+            length: info.length,
         })
     }
 
+    /// Returns only the chunks that changed since `last_version`, plus the
+    /// buffer's current version. The inner `Vec` is `None` when the bitmap
+    /// can't answer precisely (new reader, fallen-behind reader, or too
+    /// much changed to track) — callers should fall back to a full
+    /// [`read`](Self::read) in that case. See [`SharedMemoryRegion::read_dirty_since`].
+    #[cfg(target_os = "linux")]
+    pub fn read_dirty_since(
+        &self,
+        name: &str,
+        last_version: u64,
+    ) -> Result<(u64, Option<Vec<(usize, Vec<u8>)>>), SharedMemoryError> {
+        let registry = self.registry.lock()?;
+        let region = registry
+            .get(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+        region.read_dirty_since(last_version)
+    }
+
+    /// Reads data from a memio buffer.
+    ///
+    /// Lock-free on Linux: this reads through a cached `RegionHandle` under
+    /// its seqlock rather than taking `registry`'s mutex, so a writer
+    /// serialized behind that mutex doesn't stall readers (and vice versa).
+    #[cfg(target_os = "linux")]
+    pub fn read(&self, name: &str) -> Result<ReadResult, SharedMemoryError> {
+        let handle = self.region_handle(name)?;
+        let (version, data) = handle.read_consistent()?;
+        Ok(ReadResult { data, version })
+    }
+
     #[cfg(target_os = "android")]
     pub fn read(&self, name: &str) -> Result<ReadResult, SharedMemoryError> {
         let (version, data) = android::read_from_shared(name)?;
@@ -169,16 +463,23 @@ impl MemioManager {
     }
 
     /// Gets the current version of a buffer (without reading data).
+    ///
+    /// Lock-free on Linux, via the same cached `RegionHandle` as `read`.
     #[cfg(target_os = "linux")]
     pub fn version(&self, name: &str) -> Result<u64, SharedMemoryError> {
-        let registry = self.registry.lock()?;
+        self.region_handle(name)?.version_consistent()
+    }
 
-        let region = registry
+    /// Looks up the cached lock-free read handle for `name`, under
+    /// `handles`'s own (separate from `registry`'s) mutex, held only long
+    /// enough to clone the `Arc`.
+    #[cfg(target_os = "linux")]
+    fn region_handle(&self, name: &str) -> Result<Arc<RegionHandle>, SharedMemoryError> {
+        let handles = self.handles.lock()?;
+        handles
             .get(name)
-            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
-
-        let info = region.info()?;
-        Ok(info.version)
+            .cloned()
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))
     }
 
     #[cfg(target_os = "android")]
@@ -222,6 +523,7 @@ impl MemioManager {
             version,
             length: data.len(),
             capacity: buffer_info.capacity,
+            sealed: false,
         })
     }
 
@@ -230,7 +532,99 @@ impl MemioManager {
         Err(SharedMemoryError::PlatformNotSupported)
     }
 
+    /// Returns a duplicated file descriptor for buffer `name`'s backing
+    /// storage, suitable for sending to another process (e.g. via
+    /// `fd_transfer::send_fd` over a `UnixStream`) so it can attach to the
+    /// same buffer with `import_buffer_from_fd` instead of recreating it.
+    #[cfg(target_os = "linux")]
+    pub fn export_fd(&self, name: &str) -> Result<std::os::fd::RawFd, SharedMemoryError> {
+        let registry = self.registry.lock()?;
+        let region = registry
+            .get(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+        region
+            .export_fd()
+            .map_err(|e| SharedMemoryError::Io(e.to_string()))
+    }
+
+    /// Maps an externally supplied descriptor (typically received via
+    /// `fd_transfer::recv_fd`) into the registry under `name`, without
+    /// creating or truncating any storage — `fd` must already point at an
+    /// initialized memio file of exactly `capacity` data bytes.
+    #[cfg(target_os = "linux")]
+    pub fn import_buffer_from_fd(
+        &self,
+        name: &str,
+        fd: std::os::fd::RawFd,
+        capacity: usize,
+    ) -> Result<(), SharedMemoryError> {
+        let mut registry = self.registry.lock()?;
+        let region = registry.factory().import_fd(name, fd, capacity)?;
+        let handle = region.handle();
+        registry.insert_imported(name.to_string(), region)?;
+
+        let mut handles = self.handles.lock()?;
+        handles.insert(name.to_string(), handle);
+
+        Ok(())
+    }
+
+    /// Serializes `value` with rkyv and writes it to a buffer, the same way
+    /// `write` writes raw bytes. Pair with `access_model` to read it back
+    /// without hand-rolling the serialize/deserialize step on every call
+    /// site.
+    pub fn write_model<T>(
+        &self,
+        name: &str,
+        version: u64,
+        value: &T,
+    ) -> Result<WriteResult, SharedMemoryError>
+    where
+        T: Archive + for<'a> rkyv::Serialize<rkyv::api::high::HighSerializer<
+            rkyv::util::AlignedVec,
+            rkyv::ser::allocator::ArenaHandle<'a>,
+            RancorError,
+        >>,
+    {
+        let bytes = rkyv::to_bytes::<RancorError>(value)
+            .map_err(|e| SharedMemoryError::Serialization(e.to_string()))?;
+        self.write(name, version, &bytes)
+    }
+
+    /// Reads a buffer and validates it as an archived `T`, returning a guard
+    /// that derefs to `&rkyv::Archived<T>` pointing into the validated
+    /// bytes. Validation happens once, here; the guard just holds the bytes
+    /// alive so the archived view stays valid for as long as it's held.
+    pub fn access_model<T>(&self, name: &str) -> Result<MemioRef<T>, SharedMemoryError>
+    where
+        T: Archive,
+        T::Archived: for<'a> bytecheck::CheckBytes<rkyv::api::high::HighValidator<'a, RancorError>>,
+    {
+        let result = self.read(name)?;
+        MemioRef::new(result.data)
+    }
+
+    /// Forces a persistent buffer's mapping to stable storage via
+    /// `msync(MS_SYNC)`. `write` already flushes the range it touches for
+    /// persistent buffers, so this is for callers that want an explicit sync
+    /// point (e.g. before exiting) independent of the next write.
+    #[cfg(target_os = "linux")]
+    pub fn flush(&self, name: &str) -> Result<(), SharedMemoryError> {
+        let registry = self.registry.lock()?;
+        let region = registry
+            .get(name)
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+        region.flush()
+    }
+
     /// Blocks until a buffer version changes or timeout is reached.
+    ///
+    /// On Linux, this waits on the buffer's eventfd through epoll instead of
+    /// spinning: `write` notifies it after every version bump, so a blocked
+    /// reader wakes within microseconds of a write rather than after a full
+    /// `poll_interval`. `poll_interval` is only consulted by the polling
+    /// fallback, used if epoll itself fails to set up.
+    #[cfg(target_os = "linux")]
     pub fn wait_for_change(
         &self,
         name: &str,
@@ -239,6 +633,72 @@ impl MemioManager {
         poll_interval: Duration,
     ) -> Result<Option<ReadResult>, SharedMemoryError> {
         let start = Instant::now();
+
+        let current = self.version(name)?;
+        if current != last_version {
+            return Ok(Some(self.read(name)?));
+        }
+
+        let raw_fd = {
+            let registry = self.registry.lock()?;
+            registry
+                .raw_fd(name)
+                .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?
+        };
+
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Ok(None);
+            }
+
+            match crate::eventfd_notify::epoll_wait_eventfd(raw_fd, timeout - elapsed) {
+                Ok(_) => {
+                    // A real wake and a timeout both fall through to here: we
+                    // always re-read the version rather than trusting the
+                    // epoll result, since eventfd coalesces multiple writes
+                    // into one wake and a waiter can also be woken spuriously.
+                    let current = self.version(name)?;
+                    if current != last_version {
+                        return Ok(Some(self.read(name)?));
+                    }
+                }
+                Err(_) => {
+                    // epoll unavailable for this fd — fall back to polling
+                    // for whatever's left of the budget.
+                    return self.wait_for_change_poll(
+                        name,
+                        last_version,
+                        timeout.saturating_sub(start.elapsed()),
+                        poll_interval,
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn wait_for_change(
+        &self,
+        name: &str,
+        last_version: u64,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<Option<ReadResult>, SharedMemoryError> {
+        self.wait_for_change_poll(name, last_version, timeout, poll_interval)
+    }
+
+    /// Polling fallback for `wait_for_change`, used directly on platforms
+    /// without eventfd/epoll and as a degraded path on Linux if epoll setup
+    /// itself fails.
+    fn wait_for_change_poll(
+        &self,
+        name: &str,
+        last_version: u64,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> Result<Option<ReadResult>, SharedMemoryError> {
+        let start = Instant::now();
         loop {
             let current = self.version(name)?;
             if current != last_version {
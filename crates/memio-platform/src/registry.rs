@@ -5,13 +5,50 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use memio_core::{MemioResult, SharedMemoryError, SharedMemoryFactory, SharedMemoryRegion};
 
+/// Counter mixed into [`generate_guid`] so two entries registered in the same
+/// process within the same nanosecond still get distinct GUIDs.
+static GUID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Generates a 128-bit identifier for a freshly-registered entry, mixing the
+/// process id, a monotonic counter, and wall-clock nanoseconds — the same
+/// "unique enough, not cryptographically random" approach `LinuxSharedMemoryFactory::generate_path`
+/// already uses for its file names, just widened to 128 bits so it's safe to
+/// hand out in a manifest another process reads.
+fn generate_guid() -> u128 {
+    let pid = std::process::id() as u64;
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let counter = GUID_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    let high = nanos ^ (pid.rotate_left(32));
+    let low = counter ^ nanos.rotate_left(17);
+    ((high as u128) << 64) | (low as u128)
+}
+
 /// Entry in the registry containing both path and region.
 struct RegistryEntry<R> {
     path: PathBuf,
     region: R,
+    /// Identifies this entry across a manifest round-trip: generated fresh
+    /// each time a name is registered, so a stale manifest read by another
+    /// process can't be mistaken for the buffer that currently owns `name`
+    /// after it was removed and recreated. See [`SharedRegistry::open_from_manifest`].
+    guid: u128,
+    /// Byte capacity of `region`, cached here (rather than re-querying
+    /// `region.capacity()`) so [`SharedRegistry::write_manifest`] can include
+    /// it without needing a fallible `info()` call per entry.
+    capacity: usize,
+    /// Wakes readers blocked in `MemioManager::wait_for_change` on this
+    /// buffer. Only meaningful on Linux, where `wait_for_change` waits on it
+    /// through epoll instead of polling `version()` in a loop.
+    #[cfg(target_os = "linux")]
+    eventfd: crate::eventfd_notify::EventFd,
 }
 
 /// A registry that maps names to memio regions.
@@ -69,12 +106,72 @@ impl<F: SharedMemoryFactory> SharedRegistry<F> {
         } else {
             PathBuf::new()
         };
+        let capacity = region.capacity();
+
+        #[cfg(target_os = "linux")]
+        let eventfd = crate::eventfd_notify::EventFd::new()
+            .map_err(|e| SharedMemoryError::CreateFailed(e.to_string()))?;
+
+        self.entries.insert(
+            name,
+            RegistryEntry {
+                path,
+                region,
+                guid: generate_guid(),
+                capacity,
+                #[cfg(target_os = "linux")]
+                eventfd,
+            },
+        );
+        let _ = self.write_manifest();
+        Ok(())
+    }
+
+    /// Registers an already-constructed region (e.g. one built from an
+    /// imported fd via `LinuxSharedMemoryFactory::import_fd`) under `name`,
+    /// the same way `create_buffer` would for one it created itself.
+    pub fn insert_imported(&mut self, name: String, region: F::Region) -> Result<(), SharedMemoryError> {
+        let path = if let Ok(info) = region.info() {
+            info.path.unwrap_or_default()
+        } else {
+            PathBuf::new()
+        };
+        let capacity = region.capacity();
 
-        self.entries.insert(name, RegistryEntry { path, region });
+        #[cfg(target_os = "linux")]
+        let eventfd = crate::eventfd_notify::EventFd::new()
+            .map_err(|e| SharedMemoryError::CreateFailed(e.to_string()))?;
+
+        self.entries.insert(
+            name,
+            RegistryEntry {
+                path,
+                region,
+                guid: generate_guid(),
+                capacity,
+                #[cfg(target_os = "linux")]
+                eventfd,
+            },
+        );
         let _ = self.write_manifest();
         Ok(())
     }
 
+    /// Wakes any reader blocked in `wait_for_change` on buffer `name`.
+    #[cfg(target_os = "linux")]
+    pub fn notify_fd(&self, name: &str) {
+        if let Some(entry) = self.entries.get(name) {
+            entry.eventfd.notify();
+        }
+    }
+
+    /// Returns the raw descriptor of buffer `name`'s notification eventfd,
+    /// for registering in an epoll set.
+    #[cfg(target_os = "linux")]
+    pub fn raw_fd(&self, name: &str) -> Option<std::os::fd::RawFd> {
+        self.entries.get(name).map(|e| e.eventfd.raw_fd())
+    }
+
     /// Gets a reference to a region by name.
     pub fn get(&self, name: &str) -> Option<&F::Region> {
         self.entries.get(name).map(|e| &e.region)
@@ -108,17 +205,228 @@ impl<F: SharedMemoryFactory> SharedRegistry<F> {
         Ok(())
     }
 
+    /// Writes one structured record per entry: `name\tpath\tguid_high\tguid_low\tcapacity\tversion`,
+    /// tab-separated so `path` (which may itself contain `=`) can't be
+    /// misparsed the way the old `name=path` format risked. Read back by
+    /// [`parse_manifest`] / [`SharedRegistry::open_from_manifest`].
     fn write_manifest(&self) -> MemioResult<()> {
         let mut out = String::new();
         for (name, entry) in &self.entries {
+            let version = entry.region.info().map(|info| info.version).unwrap_or(0);
+            let guid_high = (entry.guid >> 64) as u64;
+            let guid_low = entry.guid as u64;
             out.push_str(name);
-            out.push('=');
+            out.push('\t');
             out.push_str(&entry.path.to_string_lossy());
+            out.push('\t');
+            out.push_str(&guid_high.to_string());
+            out.push('\t');
+            out.push_str(&guid_low.to_string());
+            out.push('\t');
+            out.push_str(&entry.capacity.to_string());
+            out.push('\t');
+            out.push_str(&version.to_string());
             out.push('\n');
         }
         std::fs::write(&self.manifest_path, out)?;
         Ok(())
     }
+
+    /// Serializes every registered entry — name, capacity, GUID, current
+    /// version, and live data bytes — into a single file at `path`, prefixed
+    /// by a small index header carrying [`SNAPSHOT_FORMAT_VERSION`] so a
+    /// reader built against a later format can detect and reject a mismatch
+    /// instead of misparsing. Pairs with [`Self::restore`].
+    pub fn snapshot(&self, path: impl AsRef<Path>) -> Result<(), SharedMemoryError> {
+        let mut out = Vec::new();
+        out.extend_from_slice(SNAPSHOT_MAGIC);
+        out.extend_from_slice(&SNAPSHOT_FORMAT_VERSION.to_le_bytes());
+        out.extend_from_slice(&(self.entries.len() as u32).to_le_bytes());
+
+        for (name, entry) in &self.entries {
+            let info = entry.region.info()?;
+            let data = entry.region.read()?;
+
+            let name_bytes = name.as_bytes();
+            out.extend_from_slice(&(name_bytes.len() as u32).to_le_bytes());
+            out.extend_from_slice(name_bytes);
+            out.extend_from_slice(&(entry.capacity as u64).to_le_bytes());
+            out.extend_from_slice(&entry.guid.to_le_bytes());
+            out.extend_from_slice(&info.version.to_le_bytes());
+            out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            out.extend_from_slice(&data);
+        }
+
+        std::fs::write(path, out)?;
+        Ok(())
+    }
+
+    /// Rebuilds a registry from a file written by [`Self::snapshot`]:
+    /// recreates each entry through `factory` at its recorded capacity,
+    /// writes back its saved version/data, and republishes the manifest at
+    /// `manifest_path` — bringing every buffer back exactly as it was at
+    /// snapshot time, for a warm restart or crash recovery.
+    ///
+    /// Each entry's saved data length is checked against its recorded
+    /// capacity before the region is even created, so a corrupt snapshot
+    /// whose data length exceeds its capacity fails with
+    /// [`SharedMemoryError::DataTooLarge`] instead of writing past a buffer
+    /// it just allocated. A truncated snapshot fails with
+    /// [`SharedMemoryError::InvalidHeader`] when a field runs past its end.
+    pub fn restore(
+        factory: F,
+        manifest_path: PathBuf,
+        snapshot_path: impl AsRef<Path>,
+    ) -> Result<Self, SharedMemoryError> {
+        let bytes = std::fs::read(snapshot_path)?;
+        let mut cursor = 0usize;
+
+        let magic = take_bytes(&bytes, &mut cursor, SNAPSHOT_MAGIC.len())?;
+        if magic != SNAPSHOT_MAGIC {
+            return Err(SharedMemoryError::InvalidHeader);
+        }
+        let format_version = take_u32(&bytes, &mut cursor)?;
+        if format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(SharedMemoryError::Protocol(format!(
+                "unsupported registry snapshot format version {format_version}"
+            )));
+        }
+        let entry_count = take_u32(&bytes, &mut cursor)?;
+
+        let mut registry = Self {
+            factory,
+            manifest_path,
+            entries: HashMap::new(),
+        };
+
+        for _ in 0..entry_count {
+            let name_len = take_u32(&bytes, &mut cursor)? as usize;
+            let name = String::from_utf8(take_bytes(&bytes, &mut cursor, name_len)?.to_vec())
+                .map_err(|_| SharedMemoryError::InvalidHeader)?;
+            let capacity = take_u64(&bytes, &mut cursor)? as usize;
+            let guid = take_u128(&bytes, &mut cursor)?;
+            let version = take_u64(&bytes, &mut cursor)?;
+            let data_len = take_u64(&bytes, &mut cursor)? as usize;
+            let data = take_bytes(&bytes, &mut cursor, data_len)?.to_vec();
+
+            if data.len() > capacity {
+                return Err(SharedMemoryError::DataTooLarge {
+                    data_len: data.len(),
+                    capacity,
+                });
+            }
+
+            let mut region = registry.factory.create(&name, capacity)?;
+            region.write(version, &data)?;
+            let path = if let Ok(info) = region.info() {
+                info.path.unwrap_or_default()
+            } else {
+                PathBuf::new()
+            };
+
+            #[cfg(target_os = "linux")]
+            let eventfd = crate::eventfd_notify::EventFd::new()
+                .map_err(|e| SharedMemoryError::CreateFailed(e.to_string()))?;
+
+            registry.entries.insert(
+                name,
+                RegistryEntry {
+                    path,
+                    region,
+                    guid,
+                    capacity,
+                    #[cfg(target_os = "linux")]
+                    eventfd,
+                },
+            );
+        }
+
+        registry.set_env()?;
+        let _ = registry.write_manifest();
+        Ok(registry)
+    }
+}
+
+/// Magic bytes identifying a [`SharedRegistry::snapshot`] file.
+const SNAPSHOT_MAGIC: &[u8; 8] = b"MEMIOSNP";
+/// Bumped whenever the snapshot file layout changes; [`SharedRegistry::restore`]
+/// rejects anything else rather than misparsing it.
+const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+fn take_bytes<'a>(bytes: &'a [u8], cursor: &mut usize, len: usize) -> Result<&'a [u8], SharedMemoryError> {
+    let end = cursor
+        .checked_add(len)
+        .filter(|&end| end <= bytes.len())
+        .ok_or(SharedMemoryError::InvalidHeader)?;
+    let slice = &bytes[*cursor..end];
+    *cursor = end;
+    Ok(slice)
+}
+
+fn take_u32(bytes: &[u8], cursor: &mut usize) -> Result<u32, SharedMemoryError> {
+    Ok(u32::from_le_bytes(take_bytes(bytes, cursor, 4)?.try_into().unwrap()))
+}
+
+fn take_u64(bytes: &[u8], cursor: &mut usize) -> Result<u64, SharedMemoryError> {
+    Ok(u64::from_le_bytes(take_bytes(bytes, cursor, 8)?.try_into().unwrap()))
+}
+
+fn take_u128(bytes: &[u8], cursor: &mut usize) -> Result<u128, SharedMemoryError> {
+    Ok(u128::from_le_bytes(take_bytes(bytes, cursor, 16)?.try_into().unwrap()))
+}
+
+/// One parsed record from a manifest written by [`SharedRegistry::write_manifest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ManifestEntry {
+    pub(crate) path: PathBuf,
+    pub(crate) guid: u128,
+    pub(crate) capacity: usize,
+    pub(crate) version: u64,
+}
+
+/// Parses a manifest file, returning the record for `name` if present.
+fn parse_manifest(manifest_path: &Path, name: &str) -> MemioResult<Option<ManifestEntry>> {
+    Ok(parse_manifest_all(manifest_path)?.remove(name))
+}
+
+/// Parses every record in a manifest file into a name→entry map. Used both
+/// by [`parse_manifest`]'s single-name lookup and by
+/// [`crate::registry_watcher::RegistryWatcher`], which needs the full set to
+/// diff against its last snapshot. Malformed lines (wrong field count,
+/// unparseable numbers) are skipped rather than failing the whole read,
+/// since a manifest mid-rewrite by its owning process can momentarily
+/// contain a partial line.
+pub(crate) fn parse_manifest_all(manifest_path: &Path) -> MemioResult<HashMap<String, ManifestEntry>> {
+    let contents = std::fs::read_to_string(manifest_path)?;
+    let mut out = HashMap::new();
+
+    for line in contents.lines() {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let &[entry_name, path, guid_high, guid_low, capacity, version] = fields.as_slice() else {
+            continue;
+        };
+
+        let (Ok(guid_high), Ok(guid_low), Ok(capacity), Ok(version)) = (
+            guid_high.parse::<u64>(),
+            guid_low.parse::<u64>(),
+            capacity.parse::<usize>(),
+            version.parse::<u64>(),
+        ) else {
+            continue;
+        };
+
+        out.insert(
+            entry_name.to_string(),
+            ManifestEntry {
+                path: PathBuf::from(path),
+                guid: ((guid_high as u128) << 64) | (guid_low as u128),
+                capacity,
+                version,
+            },
+        );
+    }
+
+    Ok(out)
 }
 
 #[cfg(target_os = "linux")]
@@ -131,6 +439,102 @@ impl SharedRegistry<crate::LinuxSharedMemoryFactory> {
         manifest_path.push(format!("memio_shared_registry_{}.txt", std::process::id()));
         Self::new(crate::LinuxSharedMemoryFactory::new(), manifest_path)
     }
+
+    /// Looks up `name`'s region together with its GUID and capacity, the
+    /// three pieces [`crate::registry_server::RegistryServer`] needs to
+    /// answer a fd request, without exposing `RegistryEntry` itself outside
+    /// this module.
+    pub fn lookup_for_export(
+        &self,
+        name: &str,
+    ) -> Option<(&crate::LinuxSharedMemoryRegion, u128, usize)> {
+        self.entries.get(name).map(|e| (&e.region, e.guid, e.capacity))
+    }
+
+    /// Creates or re-opens a file-backed persistent buffer at `path` and
+    /// registers it under `name`, the same way `create_buffer` registers one
+    /// backed by `/dev/shm`.
+    pub fn create_persistent_buffer(
+        &mut self,
+        name: impl Into<String>,
+        path: PathBuf,
+        capacity: usize,
+    ) -> Result<(), SharedMemoryError> {
+        let name = name.into();
+        let region = self.factory.create_persistent(&name, path, capacity)?;
+        let path = if let Ok(info) = region.info() {
+            info.path.unwrap_or_default()
+        } else {
+            PathBuf::new()
+        };
+        let capacity = region.capacity();
+
+        let eventfd = crate::eventfd_notify::EventFd::new()
+            .map_err(|e| SharedMemoryError::CreateFailed(e.to_string()))?;
+
+        self.entries.insert(
+            name,
+            RegistryEntry {
+                path,
+                region,
+                guid: generate_guid(),
+                capacity,
+                eventfd,
+            },
+        );
+        let _ = self.write_manifest();
+        Ok(())
+    }
+
+    /// Reopens a region another process registered under `name`, by parsing
+    /// the structured manifest at `manifest_path` (typically the path that
+    /// process exported via `MEMIO_SHARED_REGISTRY`) and reattaching at the
+    /// recorded capacity through [`LinuxSharedMemoryFactory::create_persistent`],
+    /// which reopens an existing file-backed region unchanged rather than
+    /// truncating it.
+    ///
+    /// The manifest's `guid`/`version` pair guards against a stale read: if
+    /// the region this process just reopened reports a version older than
+    /// the one the manifest recorded, the file has since been recreated out
+    /// from under that name and this returns `SharedMemoryError::NotFound`
+    /// instead of handing back a mismatched buffer. Note this checks the
+    /// region's own reported version rather than a GUID embedded in its
+    /// header bytes — `memio-core`'s shared-memory header layout has no
+    /// spare field for one yet, so the GUID currently only round-trips
+    /// through the manifest itself.
+    pub fn open_from_manifest(
+        &mut self,
+        manifest_path: &Path,
+        name: &str,
+    ) -> Result<(), SharedMemoryError> {
+        let entry = parse_manifest(manifest_path, name)
+            .map_err(|e| SharedMemoryError::Io(e.to_string()))?
+            .ok_or_else(|| SharedMemoryError::NotFound(name.to_string()))?;
+
+        let region = self
+            .factory
+            .create_persistent(name, entry.path.clone(), entry.capacity)?;
+
+        let current_version = region.info()?.version;
+        if current_version < entry.version {
+            return Err(SharedMemoryError::NotFound(name.to_string()));
+        }
+
+        let eventfd = crate::eventfd_notify::EventFd::new()
+            .map_err(|e| SharedMemoryError::CreateFailed(e.to_string()))?;
+
+        self.entries.insert(
+            name.to_string(),
+            RegistryEntry {
+                path: entry.path,
+                region,
+                guid: entry.guid,
+                capacity: entry.capacity,
+                eventfd,
+            },
+        );
+        Ok(())
+    }
 }
 
 impl<F: SharedMemoryFactory> Drop for SharedRegistry<F> {
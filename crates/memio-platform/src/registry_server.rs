@@ -0,0 +1,152 @@
+//! Serves registered region fds to other processes over a Unix-domain
+//! control socket, for peers that don't already share a `SharedRegistry`
+//! in-process (e.g. a sandboxed helper, or a second binary entirely).
+//!
+//! Mirrors the existing `ExportToken`/`fd_transfer` fd hand-off, just with
+//! the request/response and the `SCM_RIGHTS` transfer folded into a single
+//! socket round-trip instead of the caller coordinating both separately.
+//!
+//! Protocol, per connection: the client writes the requested name and shuts
+//! down its write half; the server replies with a 1-byte status (`0` = name
+//! not registered, `1` = found), and on success 24 more bytes (`capacity` as
+//! a little-endian `u64`, then `guid` as a little-endian `u128`) followed by
+//! the region's fd as an `SCM_RIGHTS` ancillary message via
+//! [`fd_transfer::send_fd`].
+
+use std::io::{self, Read, Write};
+use std::os::fd::RawFd;
+use std::path::{Path, PathBuf};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::sync::{Arc, Mutex};
+
+use crate::fd_transfer;
+use crate::linux::{LinuxSharedMemoryFactory, LinuxSharedMemoryRegion};
+use crate::registry::SharedRegistry;
+
+/// Listens on a Unix-domain socket and answers fd requests for whatever
+/// names are currently registered in the `SharedRegistry` it was bound to.
+/// Authorizes purely by name: a request for anything not in the registry
+/// gets the "not found" status and nothing else.
+pub struct RegistryServer {
+    socket_path: PathBuf,
+}
+
+impl RegistryServer {
+    /// Binds `socket_path` and starts serving requests against `registry` on
+    /// a background thread (one further thread per accepted connection).
+    /// Removes a stale socket file left at `socket_path` by a previous run
+    /// before binding, the same way a crashed process's manifest file would
+    /// need cleaning up.
+    pub fn bind(
+        socket_path: impl Into<PathBuf>,
+        registry: Arc<Mutex<SharedRegistry<LinuxSharedMemoryFactory>>>,
+    ) -> io::Result<Self> {
+        let socket_path = socket_path.into();
+        if socket_path.exists() {
+            std::fs::remove_file(&socket_path)?;
+        }
+        let listener = UnixListener::bind(&socket_path)?;
+
+        std::thread::spawn(move || {
+            for conn in listener.incoming() {
+                let Ok(stream) = conn else { continue };
+                let registry = Arc::clone(&registry);
+                std::thread::spawn(move || {
+                    if let Err(e) = handle_connection(stream, &registry) {
+                        tracing::warn!("[MemioRegistryServer] connection error: {e}");
+                    }
+                });
+            }
+        });
+
+        Ok(Self { socket_path })
+    }
+
+    /// The socket path this server is listening on.
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+}
+
+impl Drop for RegistryServer {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+fn handle_connection(
+    mut stream: UnixStream,
+    registry: &Mutex<SharedRegistry<LinuxSharedMemoryFactory>>,
+) -> io::Result<()> {
+    let mut name_buf = Vec::new();
+    stream.read_to_end(&mut name_buf)?;
+    let name = String::from_utf8_lossy(&name_buf).into_owned();
+
+    let (fd, capacity, guid) = {
+        let registry = registry
+            .lock()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?;
+        let Some((region, guid, capacity)) = registry.lookup_for_export(&name) else {
+            stream.write_all(&[0u8])?;
+            return Ok(());
+        };
+        (region.export_fd()?, capacity, guid)
+    };
+
+    let mut response = Vec::with_capacity(25);
+    response.push(1u8);
+    response.extend_from_slice(&(capacity as u64).to_le_bytes());
+    response.extend_from_slice(&guid.to_le_bytes());
+    stream.write_all(&response)?;
+
+    let result = fd_transfer::send_fd(&stream, fd);
+    unsafe { libc::close(fd) };
+    result
+}
+
+/// Connects to a [`RegistryServer`] and requests a single region by name.
+pub struct RegistryClient;
+
+impl RegistryClient {
+    /// Requests `name` from the server listening at `socket_path`, wraps the
+    /// received fd in a region via `factory.import_fd`, and returns it
+    /// alongside the GUID the server reported for it. Callers that already
+    /// know `name`'s expected GUID (e.g. from a manifest snapshot via
+    /// `SharedRegistry::open_from_manifest`) can compare the two to detect a
+    /// buffer that was recreated between the manifest read and this call.
+    pub fn open(
+        socket_path: impl AsRef<Path>,
+        factory: &LinuxSharedMemoryFactory,
+        name: &str,
+    ) -> Result<(LinuxSharedMemoryRegion, u128), memio_core::MemioError> {
+        let mut stream = UnixStream::connect(socket_path.as_ref())
+            .map_err(|e| memio_core::MemioError::Io(e.to_string()))?;
+
+        stream
+            .write_all(name.as_bytes())
+            .map_err(|e| memio_core::MemioError::Io(e.to_string()))?;
+        stream
+            .shutdown(std::net::Shutdown::Write)
+            .map_err(|e| memio_core::MemioError::Io(e.to_string()))?;
+
+        let mut status = [0u8; 1];
+        stream
+            .read_exact(&mut status)
+            .map_err(|e| memio_core::MemioError::Io(e.to_string()))?;
+        if status[0] == 0 {
+            return Err(memio_core::MemioError::NotFound(name.to_string()));
+        }
+
+        let mut body = [0u8; 24];
+        stream
+            .read_exact(&mut body)
+            .map_err(|e| memio_core::MemioError::Io(e.to_string()))?;
+        let capacity = u64::from_le_bytes(body[0..8].try_into().unwrap()) as usize;
+        let guid = u128::from_le_bytes(body[8..24].try_into().unwrap());
+
+        let fd: RawFd = fd_transfer::recv_fd(&stream).map_err(fd_transfer::recv_fd_err)?;
+
+        let region = factory.import_fd(name, fd, capacity)?;
+        Ok((region, guid))
+    }
+}
@@ -0,0 +1,235 @@
+//! Event-driven alternative to polling a registry manifest for changes.
+//!
+//! A process that wants to discover another process's buffers today has to
+//! re-read the manifest published via `MEMIO_SHARED_REGISTRY` on a timer.
+//! [`RegistryWatcher`] instead watches that file (and its parent directory,
+//! so an atomic-replace writer that unlinks and recreates it is still
+//! caught) with `inotify` and reports what changed since the last time it
+//! was asked.
+
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CString;
+use std::io;
+use std::os::fd::RawFd;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::registry::{parse_manifest_all, ManifestEntry};
+
+/// A change observed in a registry manifest since the last snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryEvent {
+    /// A name present now that wasn't in the previous snapshot.
+    Added(String),
+    /// A name from the previous snapshot that's no longer present.
+    Removed(String),
+    /// A name present in both snapshots whose GUID or version changed —
+    /// the buffer was rewritten, or replaced out from under the same name.
+    Changed(String, u64),
+}
+
+/// Watches a registry manifest file for changes and reports them as
+/// [`RegistryEvent`]s, without the caller having to poll.
+///
+/// Internally this watches `IN_CLOSE_WRITE | IN_MODIFY` on the manifest file
+/// itself and `IN_MOVED_TO | IN_CREATE` on its parent directory, so both a
+/// writer that truncates the file in place (what [`crate::SharedRegistry`]
+/// does today) and one that builds a new file and renames it over the old
+/// one are observed. Multiple inotify wake-ups that land before the caller
+/// next polls are coalesced into a single re-parse-and-diff rather than
+/// replayed one at a time.
+pub struct RegistryWatcher {
+    inotify_fd: RawFd,
+    manifest_path: PathBuf,
+    snapshot: HashMap<String, ManifestEntry>,
+    pending: VecDeque<RegistryEvent>,
+}
+
+impl RegistryWatcher {
+    /// Starts watching `manifest_path`, taking its current contents (if any)
+    /// as the baseline snapshot — only changes from this point on are
+    /// reported.
+    pub fn new(manifest_path: impl Into<PathBuf>) -> io::Result<Self> {
+        let manifest_path = manifest_path.into();
+
+        let inotify_fd = unsafe { libc::inotify_init1(libc::IN_CLOEXEC | libc::IN_NONBLOCK) };
+        if inotify_fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let dir = match manifest_path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let dir_wd = unsafe {
+            libc::inotify_add_watch(
+                inotify_fd,
+                path_to_cstring(dir)?.as_ptr(),
+                libc::IN_MOVED_TO | libc::IN_CREATE,
+            )
+        };
+        if dir_wd < 0 {
+            let err = io::Error::last_os_error();
+            unsafe { libc::close(inotify_fd) };
+            return Err(err);
+        }
+
+        // Best effort: the manifest may not exist yet if the watcher starts
+        // before the owning process's first `write_manifest` call — the
+        // directory watch above still catches it once it's created.
+        let _ = unsafe {
+            libc::inotify_add_watch(
+                inotify_fd,
+                path_to_cstring(&manifest_path)?.as_ptr(),
+                libc::IN_CLOSE_WRITE | libc::IN_MODIFY,
+            )
+        };
+
+        let snapshot = parse_manifest_all(&manifest_path).unwrap_or_default();
+
+        Ok(Self {
+            inotify_fd,
+            manifest_path,
+            snapshot,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Blocks for up to `timeout` waiting for the manifest to change, then
+    /// returns the next event, or `None` if `timeout` elapsed with nothing
+    /// pending. It also returns `None` early when a wake-up finds the
+    /// manifest unreadable or unchanged from the last snapshot. A single wake-up can surface several changes at once (e.g.
+    /// two buffers registered back-to-back before this was next called);
+    /// those queue up here and drain one per call instead of being lost.
+    pub fn next_event(&mut self, timeout: Duration) -> io::Result<Option<RegistryEvent>> {
+        if let Some(event) = self.pending.pop_front() {
+            return Ok(Some(event));
+        }
+
+        if !epoll_wait_fd(self.inotify_fd, timeout)? {
+            return Ok(None);
+        }
+        drain_inotify_events(self.inotify_fd)?;
+
+        // Re-arm the file watch in case the manifest was just recreated
+        // (unlink + rename) rather than truncated in place — re-adding a
+        // watch that's still valid is a harmless no-op.
+        let _ = unsafe {
+            libc::inotify_add_watch(
+                self.inotify_fd,
+                path_to_cstring(&self.manifest_path)?.as_ptr(),
+                libc::IN_CLOSE_WRITE | libc::IN_MODIFY,
+            )
+        };
+
+        // The manifest can be transiently missing or half-written mid
+        // `write_manifest`; treat that as "nothing to report yet" and let
+        // the next wake-up (its eventual IN_CLOSE_WRITE/IN_CREATE) retry.
+        let current = match parse_manifest_all(&self.manifest_path) {
+            Ok(current) => current,
+            Err(_) => return Ok(None),
+        };
+
+        self.pending.extend(diff_snapshots(&self.snapshot, &current));
+        self.snapshot = current;
+
+        Ok(self.pending.pop_front())
+    }
+}
+
+impl Drop for RegistryWatcher {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.inotify_fd);
+        }
+    }
+}
+
+// Safety: the fd is only ever touched through the syscalls in this module,
+// all of which are safe to call from any thread.
+unsafe impl Send for RegistryWatcher {}
+unsafe impl Sync for RegistryWatcher {}
+
+/// Diffs two manifest snapshots into the events needed to go from `old` to
+/// `new`.
+fn diff_snapshots(
+    old: &HashMap<String, ManifestEntry>,
+    new: &HashMap<String, ManifestEntry>,
+) -> Vec<RegistryEvent> {
+    let mut events = Vec::new();
+
+    for (name, entry) in new {
+        match old.get(name) {
+            None => events.push(RegistryEvent::Added(name.clone())),
+            Some(prev) if prev.guid != entry.guid || prev.version != entry.version => {
+                events.push(RegistryEvent::Changed(name.clone(), entry.version));
+            }
+            _ => {}
+        }
+    }
+    for name in old.keys() {
+        if !new.contains_key(name) {
+            events.push(RegistryEvent::Removed(name.clone()));
+        }
+    }
+
+    events
+}
+
+fn path_to_cstring(path: &Path) -> io::Result<CString> {
+    CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+/// Blocks on `fd` through a single-fd epoll set for up to `timeout`.
+/// Returns `Ok(true)` if `fd` became readable, `Ok(false)` on timeout.
+fn epoll_wait_fd(fd: RawFd, timeout: Duration) -> io::Result<bool> {
+    let epfd = unsafe { libc::epoll_create1(libc::EPOLL_CLOEXEC) };
+    if epfd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut event = libc::epoll_event {
+        events: libc::EPOLLIN as u32,
+        u64: fd as u64,
+    };
+    let ctl = unsafe { libc::epoll_ctl(epfd, libc::EPOLL_CTL_ADD, fd, &mut event) };
+    if ctl < 0 {
+        let err = io::Error::last_os_error();
+        unsafe { libc::close(epfd) };
+        return Err(err);
+    }
+
+    let timeout_ms = timeout.as_millis().min(i32::MAX as u128) as i32;
+    let mut events = [libc::epoll_event { events: 0, u64: 0 }; 1];
+    let n = unsafe { libc::epoll_wait(epfd, events.as_mut_ptr(), 1, timeout_ms) };
+    unsafe { libc::close(epfd) };
+
+    if n < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(n > 0)
+}
+
+/// Drains every pending inotify event off `fd` without interpreting them —
+/// `RegistryWatcher` re-parses the whole manifest on any wake-up rather than
+/// tracking individual event payloads, so this only needs to empty the fd's
+/// read buffer so a level-triggered epoll doesn't immediately trip again on
+/// data this call already accounted for.
+fn drain_inotify_events(fd: RawFd) -> io::Result<()> {
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) };
+        if n < 0 {
+            let err = io::Error::last_os_error();
+            if err.kind() == io::ErrorKind::WouldBlock {
+                return Ok(());
+            }
+            return Err(err);
+        }
+        if n == 0 {
+            return Ok(());
+        }
+    }
+}
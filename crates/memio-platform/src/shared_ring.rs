@@ -4,21 +4,71 @@
 
 use std::fs::OpenOptions;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicI32, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 
 use memmap2::MmapMut;
 
-use memio_core::{MemioError, MemioResult};
+use memio_core::{ChangeLog, MemioError, MemioResult};
 
 const RING_MAGIC: u64 = 0x5455_5242_4F52_494E; // "MEMIORIN"
 static RING_COUNTER: AtomicU64 = AtomicU64::new(0);
 
+/// Size of a message-mode record header: `[i32 length][u32 type_id]`.
+const RECORD_HEADER_SIZE: usize = 8;
+
+/// Reserved `type_id` for a padding record, modeled on the Aeron broadcast
+/// buffer layout: emitted when a real record would otherwise straddle the
+/// physical end of the data region, so [`SharedRingBuffer::read_message`]
+/// can skip it transparently and wrap the reader to offset 0 along with it.
+const PADDING_TYPE_ID: u32 = u32::MAX;
+
+/// Rounds `n` up to the next multiple of 8, matching the Aeron-style record
+/// alignment [`SharedRingBuffer::write_message`] pads every record to.
+fn align8(n: usize) -> usize {
+    (n + 7) & !7
+}
+
+/// Typical L1 cache line size; fields that producer and consumer each own
+/// are spaced this far apart so a store to one never invalidates the
+/// other's line, following Aeron's `CACHE_LINE_LENGTH`-spaced ring-buffer
+/// descriptor layout.
+const CACHE_LINE: usize = 64;
+
 #[repr(C)]
 struct RingHeader {
+    // Read-mostly metadata, touched only at open time — its own line so it
+    // never shares a line with the hot head/tail traffic below.
     magic: u64,
     capacity: u64,
+    _pad_meta: [u8; CACHE_LINE - 16],
+
+    // Producer-owned: `head` is the real write cursor, `tail_cache` is the
+    // producer's cached copy of `tail` so `write`/`write_message` can check
+    // "is there room?" without touching the consumer's cache line — only
+    // refreshed from the real (consumer-owned) `tail` when the cached value
+    // says the ring looks too full.
     head: AtomicU64,
+    tail_cache: AtomicU64,
+    _pad_head: [u8; CACHE_LINE - 16],
+
+    // Consumer-owned: `tail` is the real read cursor, `head_cache` is the
+    // consumer's cached copy of `head`, refreshed the same way in reverse.
     tail: AtomicU64,
+    head_cache: AtomicU64,
+    _pad_tail: [u8; CACHE_LINE - 16],
+
+    /// Bumped after every successful `write`/`read`/`write_message`/
+    /// `read_message` that changes the ring's contents, and woken via
+    /// `FUTEX_WAKE` right after — the futex word [`read_blocking`]/
+    /// [`write_blocking`] park on instead of sleeping-and-retrying. Kept off
+    /// the head/tail lines so futex traffic never contends with them either.
+    ///
+    /// [`read_blocking`]: SharedRingBuffer::read_blocking
+    /// [`write_blocking`]: SharedRingBuffer::write_blocking
+    notify: AtomicU32,
+    _pad_notify: [u8; CACHE_LINE - 4],
 }
 
 /// A shared ring buffer backed by a memory-mapped file.
@@ -27,6 +77,24 @@ pub struct SharedRingBuffer {
     mmap: MmapMut,
     data_offset: usize,
     capacity: usize,
+    /// When set, `head`/`tail` and committed record framing are re-validated
+    /// on every operation and rejected with [`MemioError::CorruptRing`]
+    /// instead of trusted outright — protects against a corrupted file or a
+    /// malicious peer driving a read/write out of bounds. On by default for
+    /// [`open`](Self::open), since the file there may not be ours; off for
+    /// [`create`](Self::create), since we just initialized the header
+    /// ourselves.
+    validate_peers: bool,
+}
+
+impl std::fmt::Debug for SharedRingBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedRingBuffer")
+            .field("path", &self.path)
+            .field("capacity", &self.capacity)
+            .field("validate_peers", &self.validate_peers)
+            .finish()
+    }
 }
 
 impl SharedRingBuffer {
@@ -46,9 +114,7 @@ impl SharedRingBuffer {
     /// Opens an existing ring buffer from the given path.
     pub fn open(path: impl AsRef<Path>) -> MemioResult<Self> {
         let path = path.as_ref().to_path_buf();
-        let metadata = std::fs::metadata(&path)?;
-        let size = metadata.len() as usize;
-        Self::open_or_create(path, size, false)
+        Self::open_or_create(path, 0, false)
     }
 
     /// Returns the path to the ring buffer file.
@@ -61,6 +127,60 @@ impl SharedRingBuffer {
         self.capacity
     }
 
+    /// Enables or disables validation of `head`/`tail` and committed record
+    /// framing against a corrupt or malicious peer. See
+    /// [`validate_peers`](Self::validate_peers) for what's checked.
+    pub fn set_validation(&mut self, enabled: bool) {
+        self.validate_peers = enabled;
+    }
+
+    /// Rejects `head`/`tail` that a correct peer could never have produced:
+    /// `used = head.wrapping_sub(tail)` must not exceed `capacity`, since a
+    /// legitimate writer never lets the ring hold more bytes than it can.
+    /// A no-op when [`validate_peers`](Self::validate_peers) is disabled.
+    fn check_indices(&self, head: u64, tail: u64) -> MemioResult<()> {
+        if !self.validate_peers {
+            return Ok(());
+        }
+        let used = head.wrapping_sub(tail);
+        if used > self.capacity as u64 {
+            return Err(MemioError::CorruptRing(format!(
+                "head/tail desync: {} bytes in use exceeds capacity {}",
+                used, self.capacity
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns a `tail` the producer can use to compute free space for a
+    /// write of `want` bytes against `head`, touching the consumer-owned
+    /// `tail` cache line only if the producer's cached copy doesn't already
+    /// show enough room.
+    fn producer_tail(&self, header: &RingHeader, head: u64, want: usize) -> u64 {
+        let cached = header.tail_cache.load(Ordering::Relaxed);
+        let used = head.wrapping_sub(cached);
+        if (self.capacity as u64).saturating_sub(used) >= want as u64 {
+            return cached;
+        }
+        let fresh = header.tail.load(Ordering::Acquire);
+        header.tail_cache.store(fresh, Ordering::Relaxed);
+        fresh
+    }
+
+    /// Returns a `head` the consumer can use to compute available bytes
+    /// against `tail`, touching the producer-owned `head` cache line only
+    /// if the consumer's cached copy doesn't already show enough data.
+    fn consumer_head(&self, header: &RingHeader, tail: u64, want: usize) -> u64 {
+        let cached = header.head_cache.load(Ordering::Relaxed);
+        let available = cached.wrapping_sub(tail);
+        if available >= want as u64 {
+            return cached;
+        }
+        let fresh = header.head.load(Ordering::Acquire);
+        header.head_cache.store(fresh, Ordering::Relaxed);
+        fresh
+    }
+
     /// Writes data to the ring buffer.
     ///
     /// Returns the number of bytes written. May be less than `data.len()`
@@ -71,8 +191,10 @@ impl SharedRingBuffer {
         }
 
         let header_ptr = self.mmap.as_mut_ptr() as *mut RingHeader;
-        let head = unsafe { (*header_ptr).head.load(Ordering::Acquire) };
-        let tail = unsafe { (*header_ptr).tail.load(Ordering::Acquire) };
+        let header = unsafe { &*header_ptr };
+        let head = header.head.load(Ordering::Relaxed);
+        let tail = self.producer_tail(header, head, data.len());
+        self.check_indices(head, tail)?;
         let used = head.wrapping_sub(tail) as usize;
 
         if used >= self.capacity {
@@ -102,6 +224,7 @@ impl SharedRingBuffer {
                 .head
                 .store(head.wrapping_add(to_write as u64), Ordering::Release);
         }
+        self.wake_waiters();
         Ok(to_write)
     }
 
@@ -115,8 +238,10 @@ impl SharedRingBuffer {
         }
 
         let header_ptr = self.mmap.as_mut_ptr() as *mut RingHeader;
-        let head = unsafe { (*header_ptr).head.load(Ordering::Acquire) };
-        let tail = unsafe { (*header_ptr).tail.load(Ordering::Acquire) };
+        let header = unsafe { &*header_ptr };
+        let tail = header.tail.load(Ordering::Relaxed);
+        let head = self.consumer_head(header, tail, out.len());
+        self.check_indices(head, tail)?;
         let available = head.wrapping_sub(tail) as usize;
 
         if available == 0 {
@@ -141,13 +266,310 @@ impl SharedRingBuffer {
                 .tail
                 .store(tail.wrapping_add(to_read as u64), Ordering::Release);
         }
+        self.wake_waiters();
         Ok(to_read)
     }
 
+    /// Writes `data` as a single framed record, `[i32 length][u32 type_id][payload]`
+    /// padded up to 8-byte alignment, modeled on the Aeron broadcast/ring-buffer
+    /// layout. An opt-in alternative to [`write`](Self::write)'s raw byte stream,
+    /// for producers that need the reader to see distinct messages instead of an
+    /// undifferentiated run of bytes.
+    ///
+    /// To avoid a reader ever observing a half-written record, the writer claims
+    /// `align(HEADER + data.len(), 8)` bytes, stores the header length as `0`,
+    /// copies `type_id` and `data`, then stores the real positive length last
+    /// with `Ordering::Release` — [`read_message`](Self::read_message) loads that
+    /// length with `Ordering::Acquire` and treats `0` as "not yet committed".
+    ///
+    /// If the claimed record would run past the physical end of the data region,
+    /// a padding record (reserved `type_id`) fills the remaining bytes instead and
+    /// the write position wraps to `0`, so no record is ever physically split.
+    ///
+    /// Returns `false` if the ring doesn't have enough free space for the record
+    /// (including any padding needed to reach it).
+    pub fn write_message(&mut self, type_id: u32, data: &[u8]) -> MemioResult<bool> {
+        if type_id == PADDING_TYPE_ID {
+            return Err(MemioError::Internal(format!(
+                "type_id {} is reserved for padding records",
+                PADDING_TYPE_ID
+            )));
+        }
+
+        let record_len = align8(RECORD_HEADER_SIZE + data.len());
+
+        let header_ptr = self.mmap.as_mut_ptr() as *mut RingHeader;
+        let header = unsafe { &*header_ptr };
+        let head = header.head.load(Ordering::Relaxed);
+
+        let write_pos = (head as usize) % self.capacity;
+        let tail_room = self.capacity - write_pos;
+
+        // A padding record is only needed (and only fits) when the record
+        // doesn't reach exactly to the end of the data region.
+        let needs_pad = record_len > tail_room && tail_room > 0;
+        let pad_cost = if needs_pad { tail_room } else { 0 };
+        let required = record_len + pad_cost;
+
+        let tail = self.producer_tail(header, head, required);
+        self.check_indices(head, tail)?;
+        let used = head.wrapping_sub(tail) as usize;
+        let free = self.capacity - used;
+
+        if required > free {
+            return Ok(false);
+        }
+
+        let mut advance = 0u64;
+        if needs_pad {
+            if tail_room >= RECORD_HEADER_SIZE {
+                self.commit_record(write_pos, PADDING_TYPE_ID, &[]);
+            }
+            advance += tail_room as u64;
+        }
+
+        let write_pos = if needs_pad { 0 } else { write_pos };
+        self.commit_record(write_pos, type_id, data);
+        advance += record_len as u64;
+
+        unsafe {
+            (*header_ptr)
+                .head
+                .store(head.wrapping_add(advance), Ordering::Release);
+        }
+        self.wake_waiters();
+
+        Ok(true)
+    }
+
+    /// Returns whether [`write_message`](Self::write_message) would currently
+    /// accept a record carrying `len` bytes of payload, without claiming any
+    /// space — the same free-space and padding-cost arithmetic
+    /// `write_message` uses, read-only. Backs [`RingCredits::try_claim`].
+    pub fn has_room_for_message(&self, len: usize) -> bool {
+        let record_len = align8(RECORD_HEADER_SIZE + len);
+        let header = self.header();
+        let head = header.head.load(Ordering::Relaxed);
+
+        let write_pos = (head as usize) % self.capacity;
+        let tail_room = self.capacity - write_pos;
+        let needs_pad = record_len > tail_room && tail_room > 0;
+        let pad_cost = if needs_pad { tail_room } else { 0 };
+        let required = record_len + pad_cost;
+
+        let tail = self.producer_tail(header, head, required);
+        let used = head.wrapping_sub(tail) as usize;
+        if used > self.capacity {
+            return false;
+        }
+        required <= self.capacity - used
+    }
+
+    /// Claims the record at `pos` (length `0`), writes `type_id` and `payload`,
+    /// then commits by storing the real length last with `Ordering::Release`.
+    fn commit_record(&mut self, pos: usize, type_id: u32, payload: &[u8]) {
+        self.record_length(pos).store(0, Ordering::Relaxed);
+        self.write_type_at(pos, type_id);
+        self.write_bytes_at(pos + RECORD_HEADER_SIZE, payload);
+        self.record_length(pos)
+            .store(payload.len() as i32, Ordering::Release);
+    }
+
+    /// Dequeues the next record written by [`write_message`](Self::write_message),
+    /// skipping any padding records transparently.
+    ///
+    /// Returns `Ok(None)` if the ring is empty, or if the next record's length
+    /// hasn't been committed yet (the writer is mid-claim) — a caller should
+    /// simply retry, same as an empty ring.
+    pub fn read_message(&mut self) -> MemioResult<Option<(u32, Vec<u8>)>> {
+        loop {
+            let header_ptr = self.mmap.as_mut_ptr() as *mut RingHeader;
+            let header = unsafe { &*header_ptr };
+            let tail = header.tail.load(Ordering::Relaxed);
+            let head = self.consumer_head(header, tail, RECORD_HEADER_SIZE);
+            self.check_indices(head, tail)?;
+            if head == tail {
+                return Ok(None);
+            }
+
+            let read_pos = (tail as usize) % self.capacity;
+            // A padding record commits with length 0, and tail room smaller
+            // than a record header holds no record at all; skip both before
+            // a length of 0 is taken to mean "not yet committed".
+            if self.capacity - read_pos < RECORD_HEADER_SIZE
+                || self.read_type_at(read_pos) == PADDING_TYPE_ID
+            {
+                let skipped = (self.capacity - read_pos) as u64;
+                unsafe {
+                    (*header_ptr)
+                        .tail
+                        .store(tail.wrapping_add(skipped), Ordering::Release);
+                }
+                continue;
+            }
+
+            let length = self.record_length(read_pos).load(Ordering::Acquire);
+            if length == 0 {
+                return Ok(None);
+            }
+            if length < 0 {
+                return Err(MemioError::CorruptRing(format!(
+                    "record at offset {} has negative length {}",
+                    read_pos, length
+                )));
+            }
+
+            let type_id = self.read_type_at(read_pos);
+
+            let payload_len = length as usize;
+            let record_len = align8(RECORD_HEADER_SIZE + payload_len) as u64;
+            if self.validate_peers {
+                if read_pos + RECORD_HEADER_SIZE + payload_len > self.capacity {
+                    return Err(MemioError::CorruptRing(format!(
+                        "record at offset {} with payload of {} bytes runs past the end of the data region ({} bytes)",
+                        read_pos, payload_len, self.capacity
+                    )));
+                }
+                if record_len > head.wrapping_sub(tail) {
+                    return Err(MemioError::CorruptRing(format!(
+                        "record length {} at offset {} exceeds the {} committed bytes",
+                        record_len,
+                        read_pos,
+                        head.wrapping_sub(tail)
+                    )));
+                }
+            }
+            let payload = self.read_bytes_at(read_pos + RECORD_HEADER_SIZE, payload_len);
+
+            unsafe {
+                (*header_ptr)
+                    .tail
+                    .store(tail.wrapping_add(record_len), Ordering::Release);
+            }
+            self.wake_waiters();
+
+            return Ok(Some((type_id, payload)));
+        }
+    }
+
+    /// Blocks until `write` can place at least one byte, then writes as much
+    /// of `data` as fits (same partial-write semantics as [`write`](Self::write)),
+    /// parking on the ring's futex word between attempts instead of spinning.
+    pub fn write_blocking(&mut self, data: &[u8]) -> MemioResult<usize> {
+        self.write_blocking_timeout(data, None)
+    }
+
+    /// Like [`write_blocking`](Self::write_blocking), but gives up and returns
+    /// `Ok(0)` if `timeout` elapses before any space frees up.
+    pub fn write_blocking_timeout(
+        &mut self,
+        data: &[u8],
+        timeout: impl Into<Option<Duration>>,
+    ) -> MemioResult<usize> {
+        let deadline = timeout.into().map(|d| Instant::now() + d);
+        loop {
+            let last_seen = self.notify_word().load(Ordering::Acquire);
+            let written = self.write(data)?;
+            if written > 0 || data.is_empty() {
+                return Ok(written);
+            }
+
+            let Some(wait) = remaining_wait(deadline) else {
+                return Ok(0);
+            };
+            futex_wait(self.notify_word(), last_seen, wait);
+        }
+    }
+
+    /// Blocks until `read` has at least one byte to return, then reads as
+    /// many as fit in `out` (same partial-read semantics as [`read`](Self::read)),
+    /// parking on the ring's futex word between attempts instead of spinning.
+    pub fn read_blocking(&mut self, out: &mut [u8]) -> MemioResult<usize> {
+        self.read_blocking_timeout(out, None)
+    }
+
+    /// Like [`read_blocking`](Self::read_blocking), but gives up and returns
+    /// `Ok(0)` if `timeout` elapses before any data arrives.
+    pub fn read_blocking_timeout(
+        &mut self,
+        out: &mut [u8],
+        timeout: impl Into<Option<Duration>>,
+    ) -> MemioResult<usize> {
+        let deadline = timeout.into().map(|d| Instant::now() + d);
+        loop {
+            let last_seen = self.notify_word().load(Ordering::Acquire);
+            let read = self.read(out)?;
+            if read > 0 || out.is_empty() {
+                return Ok(read);
+            }
+
+            let Some(wait) = remaining_wait(deadline) else {
+                return Ok(0);
+            };
+            futex_wait(self.notify_word(), last_seen, wait);
+        }
+    }
+
+    fn notify_word(&self) -> &AtomicU32 {
+        &self.header().notify
+    }
+
+    /// Bumps the notify word and wakes any `FUTEX_WAIT`ers, called after
+    /// every operation that changes what a blocked `read_blocking`/
+    /// `write_blocking` caller would see.
+    fn wake_waiters(&self) {
+        self.notify_word().fetch_add(1, Ordering::Release);
+        futex_wake(self.notify_word());
+    }
+
+    fn record_length(&self, pos: usize) -> &AtomicI32 {
+        // SAFETY: `pos` is always within `self.capacity`, checked by the
+        // caller before any record is claimed at it.
+        unsafe { &*(self.mmap.as_ptr().add(self.data_offset + pos) as *const AtomicI32) }
+    }
+
+    fn write_type_at(&mut self, pos: usize, type_id: u32) {
+        let bytes = type_id.to_le_bytes();
+        let base = unsafe { self.mmap.as_mut_ptr().add(self.data_offset) };
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), base.add(pos + 4), 4);
+        }
+    }
+
+    fn read_type_at(&self, pos: usize) -> u32 {
+        let mut bytes = [0u8; 4];
+        let base = unsafe { self.mmap.as_ptr().add(self.data_offset) };
+        unsafe {
+            std::ptr::copy_nonoverlapping(base.add(pos + 4), bytes.as_mut_ptr(), 4);
+        }
+        u32::from_le_bytes(bytes)
+    }
+
+    fn write_bytes_at(&mut self, pos: usize, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        let base = unsafe { self.mmap.as_mut_ptr().add(self.data_offset) };
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), base.add(pos), data.len());
+        }
+    }
+
+    fn read_bytes_at(&self, pos: usize, len: usize) -> Vec<u8> {
+        let mut data = vec![0u8; len];
+        if len == 0 {
+            return data;
+        }
+        let base = unsafe { self.mmap.as_ptr().add(self.data_offset) };
+        unsafe {
+            std::ptr::copy_nonoverlapping(base.add(pos), data.as_mut_ptr(), len);
+        }
+        data
+    }
+
     fn open_or_create(path: PathBuf, capacity: usize, create: bool) -> MemioResult<Self> {
         let header_size = std::mem::size_of::<RingHeader>();
         let data_offset = align_up(header_size, 64);
-        let file_len = data_offset + capacity;
 
         let file = OpenOptions::new()
             .read(true)
@@ -156,37 +578,61 @@ impl SharedRingBuffer {
             .open(&path)?;
 
         if create {
+            let file_len = data_offset + capacity;
             file.set_len(file_len as u64)?;
         }
 
         let mut mmap = unsafe { MmapMut::map_mut(&file)? };
         let header_ptr = mmap.as_mut_ptr() as *mut RingHeader;
 
-        if create {
+        // The data capacity always comes from the header, not from the
+        // caller or the raw file length — `open` no longer trusts
+        // `metadata.len()` for it, since a truncated/corrupt file would
+        // otherwise mismatch what `write`/`read` actually map.
+        let capacity = if create {
             unsafe {
                 header_ptr.write(RingHeader {
                     magic: RING_MAGIC,
                     capacity: capacity as u64,
+                    _pad_meta: [0; CACHE_LINE - 16],
                     head: AtomicU64::new(0),
+                    tail_cache: AtomicU64::new(0),
+                    _pad_head: [0; CACHE_LINE - 16],
                     tail: AtomicU64::new(0),
+                    head_cache: AtomicU64::new(0),
+                    _pad_tail: [0; CACHE_LINE - 16],
+                    notify: AtomicU32::new(0),
+                    _pad_notify: [0; CACHE_LINE - 4],
                 });
             }
+            capacity
         } else {
             let header = unsafe { &*header_ptr };
             if header.magic != RING_MAGIC {
                 return Err(MemioError::Internal("Invalid ring buffer magic.".to_string()));
             }
-        }
+            let header_capacity = header.capacity as usize;
+            let expected_len = data_offset + header_capacity;
+            if mmap.len() != expected_len {
+                return Err(MemioError::CorruptRing(format!(
+                    "file is {} bytes, expected {} for a header capacity of {} bytes",
+                    mmap.len(),
+                    expected_len,
+                    header_capacity
+                )));
+            }
+            header_capacity
+        };
 
         Ok(Self {
             path,
             mmap,
             data_offset,
             capacity,
+            validate_peers: !create,
         })
     }
 
-    #[allow(dead_code)]
     fn header(&self) -> &RingHeader {
         unsafe { &*(self.mmap.as_ptr() as *const RingHeader) }
     }
@@ -195,3 +641,200 @@ impl SharedRingBuffer {
 fn align_up(value: usize, align: usize) -> usize {
     (value + align - 1) & !(align - 1)
 }
+
+/// `type_id` tag the [`ChangeLog`] impl below writes every record with, so a
+/// ring shared for some other purpose never has its frames misread as a
+/// version changelog entry.
+const CHANGELOG_TYPE_ID: u32 = 1;
+
+/// Lets a [`SharedRingBuffer`] back a [`memio_core::MemioState`]'s
+/// version-delta changelog: each [`push`](ChangeLog::push) writes one
+/// `[version: u64][data]` record via [`write_message`](SharedRingBuffer::write_message);
+/// each [`poll`](ChangeLog::poll) reads one back via
+/// [`read_message`](SharedRingBuffer::read_message) and splits the version
+/// back off the front.
+impl ChangeLog for SharedRingBuffer {
+    fn push(&mut self, version: u64, data: &[u8]) -> MemioResult<()> {
+        let mut payload = Vec::with_capacity(8 + data.len());
+        payload.extend_from_slice(&version.to_le_bytes());
+        payload.extend_from_slice(data);
+        self.write_message(CHANGELOG_TYPE_ID, &payload)?;
+        Ok(())
+    }
+
+    fn poll(&mut self) -> MemioResult<Option<(u64, Vec<u8>)>> {
+        let Some((type_id, payload)) = self.read_message()? else {
+            return Ok(None);
+        };
+        if type_id != CHANGELOG_TYPE_ID || payload.len() < 8 {
+            return Err(MemioError::CorruptRing(format!(
+                "changelog record has unexpected type_id {} / length {} bytes",
+                type_id,
+                payload.len()
+            )));
+        }
+        let version = u64::from_le_bytes(payload[..8].try_into().unwrap());
+        Ok(Some((version, payload[8..].to_vec())))
+    }
+}
+
+/// Credit-based reservation layer over a [`SharedRingBuffer`]: instead of
+/// finding out only after [`write_message`](SharedRingBuffer::write_message)
+/// that the ring was full, a producer first reserves space with
+/// [`try_claim`](Self::try_claim)/[`claim_blocking`](Self::claim_blocking),
+/// fills the payload, then publishes it with [`Claim::commit`].
+///
+/// Multiple producer threads share one `RingCredits`; the ring itself stays
+/// single-producer underneath (matching [`SharedRingBuffer`]'s `&mut self`
+/// API) and fairness across producers comes from serializing reservations
+/// through this struct's `Mutex` rather than a separate lock-free credit
+/// counter — simpler to reason about, and the mutex already gives FIFO-ish
+/// fairness under contention. The consumer side is unaffected and keeps
+/// using a plain [`SharedRingBuffer`] via `read_message`.
+pub struct RingCredits {
+    ring: Mutex<SharedRingBuffer>,
+}
+
+impl RingCredits {
+    /// Wraps `ring` for credit-based producer access.
+    pub fn new(ring: SharedRingBuffer) -> Self {
+        Self {
+            ring: Mutex::new(ring),
+        }
+    }
+
+    /// Reserves space for a `len`-byte record without writing it, returning
+    /// `None` if the ring doesn't currently have room. The reservation is
+    /// held until the returned [`Claim`] is committed or dropped.
+    pub fn try_claim(&self, type_id: u32, len: usize) -> MemioResult<Option<Claim<'_>>> {
+        let ring = self.ring.lock()?;
+        if !ring.has_room_for_message(len) {
+            return Ok(None);
+        }
+        Ok(Some(Claim {
+            ring,
+            type_id,
+            data: vec![0u8; len],
+        }))
+    }
+
+    /// Like [`try_claim`](Self::try_claim), but parks the calling thread on
+    /// the ring's futex notify word instead of returning `None` when there
+    /// isn't room yet. Returns `Ok(None)` only once `timeout` elapses.
+    pub fn claim_blocking(
+        &self,
+        type_id: u32,
+        len: usize,
+        timeout: impl Into<Option<Duration>>,
+    ) -> MemioResult<Option<Claim<'_>>> {
+        let deadline = timeout.into().map(|d| Instant::now() + d);
+        loop {
+            let ring = self.ring.lock()?;
+            if ring.has_room_for_message(len) {
+                return Ok(Some(Claim {
+                    ring,
+                    type_id,
+                    data: vec![0u8; len],
+                }));
+            }
+
+            let last_seen = ring.notify_word().load(Ordering::Acquire);
+            let Some(wait) = remaining_wait(deadline) else {
+                return Ok(None);
+            };
+            futex_wait(ring.notify_word(), last_seen, wait);
+        }
+    }
+}
+
+/// A reserved-but-not-yet-published record, borrowed from a [`RingCredits`].
+/// Fill [`payload_mut`](Self::payload_mut) and call [`commit`](Self::commit)
+/// to publish it; dropping a `Claim` without committing simply releases the
+/// reservation (the ring was never touched), giving the caller up-front
+/// space for a record it then decides not to send.
+pub struct Claim<'a> {
+    ring: MutexGuard<'a, SharedRingBuffer>,
+    type_id: u32,
+    data: Vec<u8>,
+}
+
+impl Claim<'_> {
+    /// The claimed payload buffer, initially zeroed, to fill before
+    /// committing.
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Publishes the claimed record via `write_message` and releases the
+    /// reservation, waking any blocked consumer.
+    pub fn commit(mut self) -> MemioResult<()> {
+        let capacity = self.ring.capacity();
+        let published = self.ring.write_message(self.type_id, &self.data)?;
+        if !published {
+            // Shouldn't happen: `try_claim`/`claim_blocking` only hand out a
+            // `Claim` while holding the same lock `commit` runs under, and a
+            // consumer advancing `tail` only ever frees more space.
+            return Err(MemioError::ChannelFull {
+                used: capacity,
+                capacity,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Caps how long a single `FUTEX_WAIT` call blocks, so a blocking call with
+/// no deadline still re-checks the ring periodically (in case a wake raced
+/// with the check that preceded it) and one with a deadline never oversleeps
+/// it by more than this much.
+const MAX_WAIT: Duration = Duration::from_secs(1);
+
+/// Returns how long the next `FUTEX_WAIT` should block for: `None` once
+/// `deadline` (if any) has passed, capped at [`MAX_WAIT`] otherwise.
+fn remaining_wait(deadline: Option<Instant>) -> Option<Duration> {
+    match deadline {
+        None => Some(MAX_WAIT),
+        Some(deadline) => {
+            let now = Instant::now();
+            if now >= deadline {
+                None
+            } else {
+                Some((deadline - now).min(MAX_WAIT))
+            }
+        }
+    }
+}
+
+/// Wakes every thread parked in `FUTEX_WAIT` on `word`.
+fn futex_wake(word: &AtomicU32) {
+    let ptr = word as *const AtomicU32 as *mut u32;
+    unsafe {
+        libc::syscall(
+            libc::SYS_futex,
+            ptr,
+            libc::FUTEX_WAKE,
+            i32::MAX,
+            std::ptr::null::<libc::timespec>(),
+        );
+    }
+}
+
+/// Blocks on `word` via `FUTEX_WAIT` for up to `timeout`, returning as soon
+/// as `word` differs from `expected`, a wake arrives, or the wait times out —
+/// callers re-check the ring regardless of which of those woke them.
+fn futex_wait(word: &AtomicU32, expected: u32, timeout: Duration) {
+    let ptr = word as *const AtomicU32 as *mut u32;
+    let ts = libc::timespec {
+        tv_sec: timeout.as_secs() as i64,
+        tv_nsec: timeout.subsec_nanos() as i64,
+    };
+    unsafe {
+        libc::syscall(
+            libc::SYS_futex,
+            ptr,
+            libc::FUTEX_WAIT,
+            expected,
+            &ts as *const libc::timespec,
+        );
+    }
+}
@@ -11,14 +11,17 @@ use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
 use windows::core::PCWSTR;
-use windows::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
+use windows::Win32::Foundation::{CloseHandle, DUPLICATE_SAME_ACCESS, HANDLE, INVALID_HANDLE_VALUE};
 use windows::Win32::System::Memory::{
     CreateFileMappingW, MapViewOfFile, OpenFileMappingW, UnmapViewOfFile,
-    FILE_MAP_ALL_ACCESS, PAGE_READWRITE, MEMORY_MAPPED_VIEW_ADDRESS,
+    FILE_MAP_ALL_ACCESS, FILE_MAP_READ, PAGE_READWRITE, MEMORY_MAPPED_VIEW_ADDRESS,
+};
+use windows::Win32::System::Threading::{
+    DuplicateHandle, GetCurrentProcess, OpenProcess, PROCESS_DUP_HANDLE,
 };
 
 use memio_core::{
-    read_header, write_header_unchecked, SharedMemoryError, SharedMemoryFactory,
+    read_header, write_header_ptr, write_header_unchecked, SharedMemoryError, SharedMemoryFactory,
     SharedMemoryRegion, SharedStateInfo, SHARED_STATE_HEADER_SIZE, MemioError,
 };
 
@@ -50,6 +53,23 @@ struct RegionHandle {
 unsafe impl Send for RegionHandle {}
 unsafe impl Sync for RegionHandle {}
 
+/// Everything a peer process needs to attach to a region created elsewhere:
+/// the sizing info `import_region` has no other way to learn, plus a mapping
+/// `HANDLE` value already duplicated (via `DuplicateHandle`) into the
+/// receiving process, so it's valid there without any further syscall.
+///
+/// The handle value still has to physically reach the peer process somehow —
+/// see `windows_pipe_transfer::send_token`/`recv_token` for shipping it over
+/// a named pipe.
+#[derive(Debug)]
+pub struct ExportToken {
+    pub name: String,
+    pub capacity: usize,
+    pub total_size: usize,
+    /// Raw value of a `HANDLE` already duplicated into the target process.
+    pub handle: isize,
+}
+
 /// Windows memio region using File Mapping.
 #[derive(Debug)]
 pub struct WindowsSharedMemoryRegion {
@@ -67,6 +87,10 @@ pub struct WindowsSharedMemoryRegion {
     capacity: usize,
     /// Whether this region owns the handle (created vs opened)
     owns_handle: bool,
+    /// `true` if this view is mapped read-only and frozen — created via
+    /// `create_sealed`, which writes the payload once and then reopens the
+    /// mapping as `FILE_MAP_READ`, approximating Linux's memfd `F_SEAL_WRITE`.
+    sealed: bool,
 }
 
 // SAFETY: The raw pointer and handle are only used within synchronized operations
@@ -133,9 +157,34 @@ impl WindowsSharedMemoryRegion {
             total_size,
             capacity,
             owns_handle: true,
+            sealed: false,
         })
     }
 
+    /// Creates a named file mapping, writes `data` into it once, then
+    /// reopens the mapping's view as read-only (`FILE_MAP_READ`) so no
+    /// further writes can land — Windows has no exact equivalent of memfd's
+    /// `F_SEAL_WRITE`, so this approximates it by dropping write access to
+    /// the view entirely rather than sealing the underlying object itself.
+    pub fn create_sealed(name: &str, data: &[u8]) -> Result<Self, MemioError> {
+        let mut region = Self::create(name, data.len())?;
+        region.write(1, data)?;
+
+        let addr = MEMORY_MAPPED_VIEW_ADDRESS { Value: region.ptr as *mut _ };
+        unsafe {
+            let _ = UnmapViewOfFile(addr);
+        }
+
+        let ptr = unsafe { MapViewOfFile(region.handle, FILE_MAP_READ, 0, 0, region.total_size) };
+        if ptr.Value.is_null() {
+            return Err(MemioError::MmapFailed);
+        }
+
+        region.ptr = ptr.Value as *mut u8;
+        region.sealed = true;
+        Ok(region)
+    }
+
     /// Opens an existing named file mapping.
     pub fn open(name: &str) -> Result<Self, MemioError> {
         // Look up full mapping name and capacity from registry
@@ -176,6 +225,7 @@ impl WindowsSharedMemoryRegion {
             total_size,
             capacity,
             owns_handle: false, // We don't own this - it's a secondary view
+            sealed: false,
         })
     }
 }
@@ -216,10 +266,15 @@ impl SharedMemoryRegion for WindowsSharedMemoryRegion {
             version,
             length,
             capacity: self.capacity,
+            sealed: self.sealed,
         })
     }
 
     fn write(&mut self, version: u64, data: &[u8]) -> Result<SharedStateInfo, MemioError> {
+        if self.sealed {
+            return Err(MemioError::Sealed(self.name.clone()));
+        }
+
         if data.len() > self.capacity {
             return Err(MemioError::DataTooLarge {
                 data_len: data.len(),
@@ -244,6 +299,7 @@ impl SharedMemoryRegion for WindowsSharedMemoryRegion {
             version,
             length: data.len(),
             capacity: self.capacity,
+            sealed: self.sealed,
         })
     }
 
@@ -268,6 +324,46 @@ impl SharedMemoryRegion for WindowsSharedMemoryRegion {
     unsafe fn data_ptr_mut(&mut self) -> *mut u8 {
         unsafe { self.ptr.add(HEADER_SIZE) }
     }
+
+    /// Overrides the default `write_at` purely to reject writes against a
+    /// sealed view before touching any memory; the copy/header logic below
+    /// mirrors the default implementation in `memio_core::SharedMemoryRegion`.
+    fn write_at(
+        &mut self,
+        version: u64,
+        offset: usize,
+        data: &[u8],
+    ) -> Result<SharedStateInfo, MemioError> {
+        if self.sealed {
+            return Err(MemioError::Sealed(self.name.clone()));
+        }
+
+        let capacity = self.capacity;
+        if offset.checked_add(data.len()).is_none_or(|end| end > capacity) {
+            return Err(MemioError::InvalidRange {
+                offset,
+                len: data.len(),
+                capacity,
+            });
+        }
+
+        let mut info = self.info()?;
+        let new_length = info.length.max(offset + data.len());
+
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), self.ptr.add(HEADER_SIZE).add(offset), data.len());
+        }
+
+        std::sync::atomic::fence(std::sync::atomic::Ordering::Release);
+
+        unsafe {
+            write_header_ptr(self.ptr, version, new_length);
+        }
+
+        info.version = version;
+        info.length = new_length;
+        Ok(info)
+    }
 }
 
 /// Factory for Windows memio regions.
@@ -279,6 +375,81 @@ impl WindowsSharedMemoryFactory {
     pub fn new() -> Self {
         Self
     }
+
+    /// Builds an [`ExportToken`] for an active region, duplicating its
+    /// mapping handle directly into the process identified by `target_pid`
+    /// via `DuplicateHandle` — unlike Linux's fd-passing, there's no
+    /// ancillary-message channel for handles, so the duplication has to name
+    /// its destination process up front rather than happening implicitly
+    /// when the value crosses a socket.
+    ///
+    /// Only regions registered in `ACTIVE_REGIONS` (via `create_shared_region`)
+    /// can be exported this way, since that's the only place a live `HANDLE`
+    /// is kept around after the owning `WindowsSharedMemoryRegion` itself
+    /// might have gone out of scope.
+    pub fn export_region(&self, name: &str, target_pid: u32) -> Result<ExportToken, MemioError> {
+        let active = ACTIVE_REGIONS.lock().unwrap();
+        let region = active
+            .get(name)
+            .ok_or_else(|| MemioError::NotFound(name.to_string()))?;
+
+        let target_process = unsafe { OpenProcess(PROCESS_DUP_HANDLE, false, target_pid) }
+            .map_err(|e| MemioError::OpenFailed(format!("OpenProcess failed: {:?}", e)))?;
+
+        let mut duplicated = HANDLE::default();
+        let result = unsafe {
+            DuplicateHandle(
+                GetCurrentProcess(),
+                region.handle,
+                target_process,
+                &mut duplicated,
+                0,
+                false,
+                DUPLICATE_SAME_ACCESS,
+            )
+        };
+
+        unsafe {
+            let _ = CloseHandle(target_process);
+        }
+
+        result.map_err(|e| MemioError::Internal(format!("DuplicateHandle failed: {:?}", e)))?;
+
+        Ok(ExportToken {
+            name: name.to_string(),
+            capacity: region.capacity,
+            total_size: region.total_size,
+            handle: duplicated.0 as isize,
+        })
+    }
+
+    /// Attaches to a region from an [`ExportToken`] received from the
+    /// process that called `export_region` with this process's PID. The
+    /// token's `handle` is already valid here (that's what `DuplicateHandle`
+    /// on the exporting side accomplished), so this just maps a view of it.
+    pub fn import_region(&self, token: ExportToken) -> Result<WindowsSharedMemoryRegion, MemioError> {
+        let handle = HANDLE(token.handle as _);
+        let ptr = unsafe { MapViewOfFile(handle, FILE_MAP_ALL_ACCESS, 0, 0, token.total_size) };
+
+        if ptr.Value.is_null() {
+            unsafe {
+                let _ = CloseHandle(handle);
+            }
+            return Err(MemioError::MmapFailed);
+        }
+
+        Ok(WindowsSharedMemoryRegion {
+            name: token.name,
+            // No mapping name of our own to open by — we attached via a
+            // handle the exporter already duplicated for us.
+            mapping_name: String::new(),
+            handle,
+            ptr: ptr.Value as *mut u8,
+            total_size: token.total_size,
+            capacity: token.capacity,
+            owns_handle: false,
+        })
+    }
 }
 
 impl SharedMemoryFactory for WindowsSharedMemoryFactory {
@@ -0,0 +1,58 @@
+//! Transfers a duplicated file-mapping `HANDLE` between processes over a
+//! Windows named pipe.
+//!
+//! Pairs with `WindowsSharedMemoryFactory::export_region`/`import_region`: a
+//! process duplicates its mapping handle into the peer's process with
+//! `DuplicateHandle` (so the raw value is already valid there), then sends
+//! that value — along with the capacity/total_size the peer has no other
+//! way to learn — as plain bytes over a named pipe. Unlike `SCM_RIGHTS` on
+//! Linux, no ancillary-message machinery is needed: once `DuplicateHandle`
+//! targets the peer's PID, the handle value itself is just a number.
+
+use std::io::{Read, Write};
+
+use crate::windows::ExportToken;
+
+/// Wire format: handle value, capacity, total_size, each as little-endian
+/// `i64`/`u64`, followed by the name's UTF-8 bytes.
+fn encode(token: &ExportToken) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(24 + token.name.len());
+    buf.extend_from_slice(&(token.handle as i64).to_le_bytes());
+    buf.extend_from_slice(&(token.capacity as u64).to_le_bytes());
+    buf.extend_from_slice(&(token.total_size as u64).to_le_bytes());
+    buf.extend_from_slice(token.name.as_bytes());
+    buf
+}
+
+/// Sends an [`ExportToken`] whose handle has already been duplicated into
+/// the receiving process (via `DuplicateHandle`) over `pipe`.
+pub fn send_token(pipe: &mut impl Write, token: &ExportToken) -> std::io::Result<()> {
+    let payload = encode(token);
+    pipe.write_all(&(payload.len() as u32).to_le_bytes())?;
+    pipe.write_all(&payload)
+}
+
+/// Receives an [`ExportToken`] sent via [`send_token`]. The `handle` field
+/// is already valid in this process — the sender's `DuplicateHandle` call
+/// targeted it — so no further duplication is needed before passing it to
+/// `WindowsSharedMemoryFactory::import_region`.
+pub fn recv_token(pipe: &mut impl Read) -> std::io::Result<ExportToken> {
+    let mut len_bytes = [0u8; 4];
+    pipe.read_exact(&mut len_bytes)?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+
+    let mut payload = vec![0u8; len];
+    pipe.read_exact(&mut payload)?;
+
+    let handle = i64::from_le_bytes(payload[0..8].try_into().unwrap()) as isize;
+    let capacity = u64::from_le_bytes(payload[8..16].try_into().unwrap()) as usize;
+    let total_size = u64::from_le_bytes(payload[16..24].try_into().unwrap()) as usize;
+    let name = String::from_utf8_lossy(&payload[24..]).into_owned();
+
+    Ok(ExportToken {
+        name,
+        capacity,
+        total_size,
+        handle,
+    })
+}
@@ -6,12 +6,16 @@
 //! - nativeExists: Check if a memio region exists
 //! - nativeListRegions: List all memio regions
 //! - nativeGetDirectBuffer: Get DirectByteBuffer for direct access
+//! - nativeCreateChannelEventFd: Get an eventfd for MemioChannel wakeups
 
 use jni::objects::{JByteArray, JObject, JString, JValue};
 use jni::sys::jlong;
 use jni::JNIEnv;
 
-use memio_platform::{get_shared_ptr, has_shared_region, list_shared_regions, write_to_shared};
+use memio_platform::{
+    create_channel_eventfd, get_shared_ptr, has_shared_region, list_shared_regions,
+    write_to_shared,
+};
 
 /// Writes data to a named memio region
 /// Returns true on success, false on error
@@ -120,3 +124,20 @@ pub extern "system" fn Java_com_memio_shared_MemioSharedMemory_nativeGetDirectBu
 
     buffer.into()
 }
+
+/// Creates an `eventfd` for waking the Kotlin side when a `MemioChannel` frame
+/// arrives, so callers can `select`/poll it instead of spinning on the
+/// DirectByteBuffer's header words. Returns the fd, or -1 on error.
+#[no_mangle]
+pub extern "system" fn Java_com_memio_shared_MemioSharedMemory_nativeCreateChannelEventFd(
+    mut env: JNIEnv,
+    _class: JObject,
+) -> jlong {
+    match create_channel_eventfd() {
+        Ok(fd) => fd as jlong,
+        Err(e) => {
+            let _ = env.throw_new("java/lang/RuntimeException", format!("{:?}", e));
+            -1
+        }
+    }
+}
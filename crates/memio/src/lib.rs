@@ -36,6 +36,9 @@
 // Core types
 pub use memio_core::{
     Arena,
+    ChangeLog,
+    ChannelNotifier,
+    MemioChannel,
     MemioError,
     MemioField,
     MemioFieldType,
@@ -44,6 +47,7 @@ pub use memio_core::{
     MemioScalarType,
     MemioSchema,
     MemioState,
+    NoOpChangeLog,
     NoOpRegion,
     SharedMemoryError,
     SharedMemoryFactory,
@@ -97,7 +101,8 @@ pub mod plugin {
 pub mod prelude {
     // Core types
     pub use crate::{
-        MemioError, MemioManager, MemioResult, MemioState, ReadResult, SharedStateInfo, WriteResult,
+        ChannelNotifier, MemioChannel, MemioError, MemioManager, MemioResult, MemioState,
+        ReadResult, SharedStateInfo, WriteResult,
     };
 
     // Derive macro
@@ -117,7 +122,8 @@ pub mod prelude {
 pub mod platform {
     #[cfg(target_os = "linux")]
     pub use memio_platform::{
-        LinuxMemioShared, LinuxSharedMemoryFactory, LinuxSharedMemoryRegion, MemioShared,
+        Claim, FutexNotifier, LinuxMemioShared, LinuxSharedMemoryFactory, LinuxSharedMemoryRegion,
+        MemfdSharedMemoryFactory, MemfdSharedMemoryRegion, MemioShared, RingCredits,
         SharedFileCache, SharedRegistry, SharedRingBuffer,
     };
 
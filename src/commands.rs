@@ -0,0 +1,556 @@
+//! Unified Memio commands for Linux.
+//!
+//! These commands provide a unified API for:
+//! - `memio_upload`: Upload file from URI/path to shared memory
+//! - `memio_read`: Read data from shared memory buffer
+//! - `memio_subscribe`: Register a window for push-based change notifications
+//!
+//! The implementation uses Linux shared memory.
+
+use crate::policy::{self, MemioAction};
+use serde::{Deserialize, Serialize};
+use tauri::{command, Manager, Runtime, WebviewWindow};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadResult {
+    pub success: bool,
+    pub bytes_written: usize,
+    pub version: i64,
+    pub duration_ms: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadResult {
+    pub success: bool,
+    pub version: i64,
+    pub length: usize,
+}
+
+/// Upload a file to shared memory buffer.
+///
+/// # Arguments
+/// - `buffer_name`: Name of the shared memory buffer
+/// - `file_uri`: URI or path to the file
+#[command]
+pub async fn memio_upload<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    #[allow(non_snake_case)] fileUri: String,
+) -> Result<UploadResult, String> {
+    let start = std::time::Instant::now();
+
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Write).map_err(|e| e.to_string())?;
+
+    let file_path = if fileUri.starts_with("file://") {
+        fileUri.strip_prefix("file://").unwrap_or(&fileUri)
+    } else {
+        &fileUri
+    };
+
+    let data = std::fs::read(file_path)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let version = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(1);
+
+    manager
+        .write(&bufferName, version, &data)
+        .map_err(|e| format!("Failed to write to shared memory: {:?}", e))?;
+
+    crate::subscriptions::notify_changed(window.app_handle(), &bufferName, version, data.len());
+
+    Ok(UploadResult {
+        success: true,
+        bytes_written: data.len(),
+        version: version as i64,
+        duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+/// Read data from shared memory buffer.
+///
+/// # Arguments
+/// - `buffer_name`: Name of the shared memory buffer
+/// - `last_version`: Optional - skip read if version hasn't changed
+///
+/// # Returns
+/// ReadResult with success, version, and length.
+/// The actual data is read from shared memory by the frontend.
+#[command]
+pub fn memio_read<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    #[allow(non_snake_case)] lastVersion: Option<i64>,
+) -> Result<ReadResult, String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    let manager = window.try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let result = manager.read(&bufferName)
+        .map_err(|e| format!("Failed to read from shared memory: {:?}", e))?;
+
+    // Check if version changed
+    if let Some(last) = lastVersion {
+        if result.version as i64 <= last {
+            return Ok(ReadResult {
+                success: false,
+                version: result.version as i64,
+                length: 0,
+            });
+        }
+    }
+
+    Ok(ReadResult {
+        success: true,
+        version: result.version as i64,
+        length: result.data.len(),
+    })
+}
+
+/// A single field's bytes sliced directly out of an archived payload,
+/// returned by `memio_read_field`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldBytes {
+    pub version: i64,
+    pub offset: usize,
+    pub data: Vec<u8>,
+}
+
+/// Returns the JSON schema registered for a shared memory buffer (see
+/// `schema_registry::register_schema`), so JS can learn field offsets/types
+/// once instead of decoding the whole archive to find them.
+#[command]
+pub fn memio_describe<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+) -> Result<String, String> {
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    crate::schema_registry::describe(&bufferName)
+        .ok_or_else(|| format!("No schema registered for '{}'", bufferName))
+}
+
+/// Reads a single field out of a shared memory buffer's archived payload,
+/// without copying the rest of it.
+///
+/// If `expectedVersion` is provided and the region is no longer at that
+/// version, returns `MemioError::VersionMismatch` instead of a field slice
+/// the caller's cached schema/offsets may no longer agree with.
+#[command]
+pub fn memio_read_field<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    #[allow(non_snake_case)] fieldPath: String,
+    #[allow(non_snake_case)] expectedVersion: Option<i64>,
+) -> Result<FieldBytes, String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    let (offset, length) = crate::schema_registry::field_byte_range(&bufferName, &fieldPath)
+        .ok_or_else(|| format!("Unknown field '{}' on '{}'", fieldPath, bufferName))?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let result = manager
+        .read(&bufferName)
+        .map_err(|e| format!("Failed to read from shared memory: {:?}", e))?;
+
+    if let Some(expected) = expectedVersion {
+        if expected >= 0 && result.version != expected as u64 {
+            return Err(memio_core::MemioError::VersionMismatch {
+                expected: expected as u64,
+                actual: result.version,
+            }
+            .to_string());
+        }
+    }
+
+    if offset + length > result.data.len() {
+        return Err(format!(
+            "Field '{}' range {}..{} exceeds payload length {}",
+            fieldPath,
+            offset,
+            offset + length,
+            result.data.len()
+        ));
+    }
+
+    Ok(FieldBytes {
+        version: result.version as i64,
+        offset,
+        data: result.data[offset..offset + length].to_vec(),
+    })
+}
+
+/// A byte window read out of a buffer by `memio_read_range`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RangeBytes {
+    pub version: i64,
+    pub data: Vec<u8>,
+}
+
+/// Reads a byte range out of a shared memory buffer, copying only the
+/// requested window instead of the whole payload.
+///
+/// # Arguments
+/// - `buffer_name`: Name of the shared memory buffer
+/// - `offset`: Byte offset into the data region
+/// - `len`: Number of bytes to read
+#[command]
+pub fn memio_read_range<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    offset: usize,
+    len: usize,
+) -> Result<RangeBytes, String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let data = manager
+        .read_at(&bufferName, offset, len)
+        .map_err(|e| format!("Failed to read range from shared memory: {:?}", e))?;
+    let version = manager
+        .version(&bufferName)
+        .map_err(|e| format!("Failed to read version from shared memory: {:?}", e))?;
+
+    Ok(RangeBytes {
+        version: version as i64,
+        data,
+    })
+}
+
+/// Writes a byte range into a shared memory buffer, patching only the
+/// requested window instead of re-uploading the whole payload, and bumps
+/// the buffer's version.
+///
+/// # Arguments
+/// - `buffer_name`: Name of the shared memory buffer
+/// - `offset`: Byte offset into the data region to write at
+/// - `data`: Bytes to write at `offset`
+#[command]
+pub fn memio_write_range<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    offset: usize,
+    data: Vec<u8>,
+) -> Result<UploadResult, String> {
+    let start = std::time::Instant::now();
+
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Write).map_err(|e| e.to_string())?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let version = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(1);
+
+    let result = manager
+        .write_at(&bufferName, version, offset, &data)
+        .map_err(|e| format!("Failed to write range to shared memory: {:?}", e))?;
+
+    crate::subscriptions::notify_changed(window.app_handle(), &bufferName, result.version, result.length);
+
+    Ok(UploadResult {
+        success: true,
+        bytes_written: data.len(),
+        version: result.version as i64,
+        duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+/// Returns the JSON schema registered for a shared memory buffer, the same
+/// payload `memio_describe` serves — named to sit alongside
+/// `memio_get_field`/`memio_set_field` so the frontend has one obvious entry
+/// point for layout introspection when working with typed fields.
+#[command]
+pub fn memio_schema<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+) -> Result<String, String> {
+    memio_describe(window, bufferName)
+}
+
+/// Reads a single field out of a shared memory buffer, decoded into a JSON
+/// number (or an array of numbers for array/tuple fields) per its
+/// registered `MemioScalarType`, instead of the raw bytes `memio_read_field`
+/// returns.
+#[command]
+pub fn memio_get_field<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    #[allow(non_snake_case)] fieldName: String,
+) -> Result<serde_json::Value, String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    let field = crate::schema_registry::find_field(&bufferName, &fieldName)
+        .ok_or_else(|| format!("Unknown field '{}' on '{}'", fieldName, bufferName))?;
+    let (offset, length) = field.byte_range();
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let result = manager
+        .read(&bufferName)
+        .map_err(|e| format!("Failed to read from shared memory: {:?}", e))?;
+
+    if offset + length > result.data.len() {
+        return Err(format!(
+            "Field '{}' range {}..{} exceeds payload length {}",
+            fieldName,
+            offset,
+            offset + length,
+            result.data.len()
+        ));
+    }
+
+    crate::schema_registry::decode_field(&field.ty, &result.data[offset..offset + length])
+}
+
+/// Writes a single field into a shared memory buffer from a JSON number (or
+/// an array of numbers for array/tuple fields), encoded per its registered
+/// `MemioScalarType`, and bumps the buffer's version.
+#[command]
+pub fn memio_set_field<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    #[allow(non_snake_case)] fieldName: String,
+    value: serde_json::Value,
+) -> Result<UploadResult, String> {
+    let start = std::time::Instant::now();
+
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Write).map_err(|e| e.to_string())?;
+
+    let field = crate::schema_registry::find_field(&bufferName, &fieldName)
+        .ok_or_else(|| format!("Unknown field '{}' on '{}'", fieldName, bufferName))?;
+    let (offset, length) = field.byte_range();
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let capacity = manager
+        .info(&bufferName)
+        .map_err(|e| format!("Failed to read buffer info: {:?}", e))?
+        .capacity;
+
+    if offset + length > capacity {
+        return Err(format!(
+            "Field '{}' range {}..{} exceeds buffer capacity {}",
+            fieldName,
+            offset,
+            offset + length,
+            capacity
+        ));
+    }
+
+    let bytes = crate::schema_registry::encode_field(&field.ty, &value)?;
+    if bytes.len() != length {
+        return Err(format!(
+            "Encoded field '{}' is {} bytes, expected {}",
+            fieldName,
+            bytes.len(),
+            length
+        ));
+    }
+
+    let version = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(1);
+
+    let result = manager
+        .write_at(&bufferName, version, offset, &bytes)
+        .map_err(|e| format!("Failed to write field to shared memory: {:?}", e))?;
+
+    crate::subscriptions::notify_changed(window.app_handle(), &bufferName, result.version, result.length);
+
+    Ok(UploadResult {
+        success: true,
+        bytes_written: bytes.len(),
+        version: result.version as i64,
+        duration_ms: start.elapsed().as_secs_f64() * 1000.0,
+    })
+}
+
+/// One dirty chunk returned by `memio_read_dirty`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirtyChunk {
+    pub offset: usize,
+    pub data: Vec<u8>,
+}
+
+/// Result of `memio_read_dirty`: either the precise set of chunks that
+/// changed since `lastVersion` (`full: false`), or a signal that the caller
+/// should fall back to `memio_read`/`memio_read_range` for the whole buffer
+/// (`full: true`, `chunks` empty) because more changed than the buffer's
+/// dirty bitmap could track, or `lastVersion` wasn't the version it was
+/// tracking from.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirtyResult {
+    pub version: i64,
+    pub full: bool,
+    pub chunks: Vec<DirtyChunk>,
+}
+
+/// Returns only the chunks of a shared memory buffer that changed since
+/// `lastVersion`, instead of the whole payload, for readers that poll a
+/// large, sparsely-updated buffer. Falls back to `full: true` whenever the
+/// precise set of changed chunks isn't known (first call, a reader that
+/// missed a prior incremental read, or too many chunks changed to track) —
+/// callers must treat that as "go read everything" rather than "nothing
+/// changed".
+#[command]
+pub fn memio_read_dirty<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    #[allow(non_snake_case)] lastVersion: i64,
+) -> Result<DirtyResult, String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    let last_version = if lastVersion < 0 { 0 } else { lastVersion as u64 };
+    let (version, ranges) = manager
+        .read_dirty_since(&bufferName, last_version)
+        .map_err(|e| format!("Failed to read dirty ranges from shared memory: {:?}", e))?;
+
+    match ranges {
+        Some(ranges) => Ok(DirtyResult {
+            version: version as i64,
+            full: false,
+            chunks: ranges
+                .into_iter()
+                .map(|(offset, data)| DirtyChunk { offset, data })
+                .collect(),
+        }),
+        None => Ok(DirtyResult {
+            version: version as i64,
+            full: true,
+            chunks: Vec::new(),
+        }),
+    }
+}
+
+/// Creates a streaming ring buffer named `bufferName`: a lock-free
+/// single-producer/single-consumer queue of length-prefixed frames, for a
+/// producer emitting a stream of messages (audio frames, log lines,
+/// telemetry) that a slower consumer must drain in order without an
+/// overwrite ever clobbering an undrained frame, unlike the single-slot
+/// last-writer-wins semantics of `memio_upload`/`memio_write_range`.
+#[command]
+pub fn memio_ring_create<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    capacity: usize,
+) -> Result<(), String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Create).map_err(|e| e.to_string())?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    manager
+        .create_ring_buffer(&bufferName, capacity)
+        .map_err(|e| format!("Failed to create ring buffer: {:?}", e))
+}
+
+/// Enqueues `data` as a frame on a ring buffer created via
+/// `memio_ring_create`. Returns an error if the ring doesn't currently have
+/// enough free space for the frame.
+#[command]
+pub fn memio_ring_push<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+    data: Vec<u8>,
+) -> Result<(), String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Write).map_err(|e| e.to_string())?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    manager
+        .ring_push(&bufferName, &data)
+        .map_err(|e| format!("Failed to push ring frame: {:?}", e))
+}
+
+/// Dequeues the next frame from a ring buffer without blocking, returning
+/// `None` if it's currently empty. The frontend is expected to poll this in
+/// a loop (e.g. on its own animation frame or timer) to drain frames as a
+/// producer pushes them.
+#[command]
+pub fn memio_ring_pop<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+) -> Result<Option<Vec<u8>>, String> {
+    use memio_platform::MemioManager;
+
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    let manager = window
+        .try_state::<std::sync::Arc<MemioManager>>()
+        .ok_or("MemioManager not available")?;
+
+    manager
+        .ring_pop(&bufferName)
+        .map_err(|e| format!("Failed to pop ring frame: {:?}", e))
+}
+
+/// Subscribe the calling window to push-based change notifications for a
+/// shared memory buffer. Once subscribed, the window receives a
+/// `memio://changed` event on every subsequent write; if the buffer has
+/// already been written, the window is sent that version immediately so it
+/// doesn't have to poll `memio_read` to catch up.
+#[command]
+pub fn memio_subscribe<R: Runtime>(
+    window: WebviewWindow<R>,
+    #[allow(non_snake_case)] bufferName: String,
+) -> Result<(), String> {
+    policy::check(window.label(), &bufferName, MemioAction::Read).map_err(|e| e.to_string())?;
+
+    crate::subscriptions::subscribe(&window, &bufferName);
+    Ok(())
+}
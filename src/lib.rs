@@ -10,12 +10,48 @@ mod linux;
 pub mod windows;
 #[cfg(target_os = "windows")]
 pub mod windows_shared_buffer;
+#[cfg(target_os = "windows")]
+pub mod windows_shared_ring;
+#[cfg(target_os = "windows")]
+pub mod windows_hostobject;
+#[cfg(target_os = "windows")]
+pub mod windows_blob_server;
 
 mod commands;
-pub use commands::{memio_upload, memio_read, UploadResult, ReadResult};
+pub use commands::{
+    memio_upload, memio_read, memio_subscribe, memio_describe, memio_read_field,
+    memio_read_range, memio_write_range, memio_schema, memio_get_field, memio_set_field,
+    memio_read_dirty, memio_ring_create, memio_ring_push, memio_ring_pop,
+    UploadResult, ReadResult, FieldBytes, RangeBytes, DirtyChunk, DirtyResult,
+};
+
+pub mod subscriptions;
+pub mod schema_registry;
+
+mod policy;
+pub use policy::{MemioAction, MemioPolicyBuilder};
+
+#[cfg(target_os = "windows")]
+pub use windows_blob_server::MemioBlobServerBuilder;
 
-/// Initializes the Memio plugin.
+/// Initializes the Memio plugin. Every window may read, write, or create
+/// any region; to restrict that, use [`init_with_policy`] instead.
 pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    build_plugin(None)
+}
+
+/// Initializes the Memio plugin with an access-control policy restricting
+/// which region names each window may read, write, or create. Enforced by
+/// the command layer and `MemioHostObject::ReadSharedState`/`WriteSharedState`.
+pub fn init_with_policy<R: Runtime>(policy: MemioPolicyBuilder) -> TauriPlugin<R> {
+    build_plugin(Some(policy))
+}
+
+fn build_plugin<R: Runtime>(policy: Option<MemioPolicyBuilder>) -> TauriPlugin<R> {
+    if let Some(policy) = policy {
+        policy.install();
+    }
+
     #[cfg(target_os = "linux")]
     {
         if let Err(err) = ensure_webkit_extension_dir() {
@@ -52,6 +88,18 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
         .invoke_handler(tauri::generate_handler![
             commands::memio_upload,
             commands::memio_read,
+            commands::memio_subscribe,
+            commands::memio_describe,
+            commands::memio_read_field,
+            commands::memio_read_range,
+            commands::memio_write_range,
+            commands::memio_schema,
+            commands::memio_get_field,
+            commands::memio_set_field,
+            commands::memio_read_dirty,
+            commands::memio_ring_create,
+            commands::memio_ring_push,
+            commands::memio_ring_pop,
         ]);
 
     builder.build()
@@ -0,0 +1,149 @@
+//! Per-window capability ACLs for shared memory regions.
+//!
+//! Without this, any window able to call `memio_upload`/`memio_read` (or
+//! invoke `MemioHostObject::ReadSharedState`/`WriteSharedState`) can touch
+//! any named region, which is unsafe once an app has more than one window
+//! or loads untrusted origins. `MemioPolicyBuilder` lets a host app declare,
+//! per window label, which region-name globs that window may read, write,
+//! or create; `policy::check` is consulted by the command layer and the
+//! host object before dispatching to `memio_platform`.
+
+use memio_core::MemioError;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+static POLICY: OnceLock<MemioPolicyBuilder> = OnceLock::new();
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemioAction {
+    Read,
+    Write,
+    Create,
+}
+
+impl MemioAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Create => "create",
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct WindowGrant {
+    read: Vec<String>,
+    write: Vec<String>,
+    create: Vec<String>,
+}
+
+/// Builds the process-wide policy mapping window labels to the region-name
+/// globs they may read, write, or create. Pass the built policy to
+/// `install()` during `plugin::init()`.
+///
+/// If no policy is ever installed, every window may do everything, so
+/// existing apps that don't opt in keep today's behavior.
+#[derive(Default)]
+pub struct MemioPolicyBuilder {
+    grants: HashMap<String, WindowGrant>,
+}
+
+impl MemioPolicyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grants the window labeled `window` read access to region names
+    /// matching `glob` (only `*` is supported, e.g. `"config.*"`).
+    pub fn allow_read(mut self, window: impl Into<String>, glob: impl Into<String>) -> Self {
+        self.grants
+            .entry(window.into())
+            .or_default()
+            .read
+            .push(glob.into());
+        self
+    }
+
+    /// Grants the window labeled `window` write access to region names
+    /// matching `glob`.
+    pub fn allow_write(mut self, window: impl Into<String>, glob: impl Into<String>) -> Self {
+        self.grants
+            .entry(window.into())
+            .or_default()
+            .write
+            .push(glob.into());
+        self
+    }
+
+    /// Grants the window labeled `window` create access to region names
+    /// matching `glob`.
+    pub fn allow_create(mut self, window: impl Into<String>, glob: impl Into<String>) -> Self {
+        self.grants
+            .entry(window.into())
+            .or_default()
+            .create
+            .push(glob.into());
+        self
+    }
+
+    /// Installs this policy as the process-wide policy enforced by the
+    /// command layer and the Windows host object. Only the first call
+    /// across the process takes effect.
+    pub fn install(self) {
+        let _ = POLICY.set(self);
+    }
+}
+
+/// Returns `Err(MemioError::PermissionDenied)` if `window` is not permitted
+/// to perform `action` on region `name`. If no policy has been installed,
+/// every window may do everything.
+pub fn check(window: &str, name: &str, action: MemioAction) -> Result<(), MemioError> {
+    let Some(policy) = POLICY.get() else {
+        return Ok(());
+    };
+
+    let Some(grant) = policy.grants.get(window) else {
+        return Err(denied(window, name, action));
+    };
+
+    let globs = match action {
+        MemioAction::Read => &grant.read,
+        MemioAction::Write => &grant.write,
+        MemioAction::Create => &grant.create,
+    };
+
+    if globs.iter().any(|glob| glob_match(glob, name)) {
+        Ok(())
+    } else {
+        Err(denied(window, name, action))
+    }
+}
+
+fn denied(window: &str, name: &str, action: MemioAction) -> MemioError {
+    MemioError::PermissionDenied {
+        window: window.to_string(),
+        name: name.to_string(),
+        action: action.as_str(),
+    }
+}
+
+/// Minimal glob matcher supporting `*` as "zero or more characters". This
+/// covers the region-name-prefix case (`"config.*"`) policies need without
+/// pulling in a dependency for full glob syntax.
+fn glob_match(pattern: &str, candidate: &str) -> bool {
+    fn match_here(pattern: &[u8], candidate: &[u8]) -> bool {
+        match pattern.first() {
+            None => candidate.is_empty(),
+            Some(b'*') => {
+                (0..=candidate.len()).any(|i| match_here(&pattern[1..], &candidate[i..]))
+            }
+            Some(&c) => {
+                !candidate.is_empty()
+                    && candidate[0] == c
+                    && match_here(&pattern[1..], &candidate[1..])
+            }
+        }
+    }
+    match_here(pattern.as_bytes(), candidate.as_bytes())
+}
@@ -0,0 +1,180 @@
+//! Schema registry for zero-copy field reads.
+//!
+//! JS historically had to Base64/JSON round-trip an entire archived payload
+//! just to read one field. Once a host app registers a `MemioSchema` for a
+//! region name (typically right after creating the `MemioState` it backs),
+//! `memio_describe`/`memio_read_field` serve that layout and let JS fetch a
+//! single field's byte range directly out of the archive instead.
+
+use memio_core::{schema_json, MemioField, MemioFieldType, MemioScalarType, MemioSchema};
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+struct RegisteredSchema {
+    json: String,
+    fields: Vec<MemioField>,
+}
+
+fn registry() -> &'static Mutex<HashMap<String, RegisteredSchema>> {
+    static SCHEMAS: OnceLock<Mutex<HashMap<String, RegisteredSchema>>> = OnceLock::new();
+    SCHEMAS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Associates `name` with `T`'s schema so `memio_describe`/`memio_read_field`
+/// can serve its layout without the field list being re-sent on every call.
+pub fn register_schema<T: MemioSchema>(name: &str) {
+    let mut reg = registry().lock().unwrap();
+    reg.insert(
+        name.to_string(),
+        RegisteredSchema {
+            json: schema_json::<T>(),
+            fields: T::schema().to_vec(),
+        },
+    );
+}
+
+/// Returns the JSON schema registered for `name`, if any.
+pub fn describe(name: &str) -> Option<String> {
+    registry().lock().unwrap().get(name).map(|s| s.json.clone())
+}
+
+/// Returns the `(offset, length)` byte range of `field_path` within `name`'s
+/// registered schema, if both are known.
+pub fn field_byte_range(name: &str, field_path: &str) -> Option<(usize, usize)> {
+    registry()
+        .lock()
+        .unwrap()
+        .get(name)?
+        .fields
+        .iter()
+        .find(|f| f.name == field_path)
+        .map(|f| f.byte_range())
+}
+
+/// Returns the full registered `MemioField` for `field_path` within `name`'s
+/// schema, including its type, so callers can decode/encode a scalar or
+/// array value instead of just slicing raw bytes.
+pub fn find_field(name: &str, field_path: &str) -> Option<MemioField> {
+    registry()
+        .lock()
+        .unwrap()
+        .get(name)?
+        .fields
+        .iter()
+        .find(|f| f.name == field_path)
+        .cloned()
+}
+
+fn decode_scalar(ty: MemioScalarType, bytes: &[u8]) -> serde_json::Value {
+    match ty {
+        MemioScalarType::U8 => serde_json::json!(bytes[0]),
+        MemioScalarType::U16 => serde_json::json!(u16::from_le_bytes(bytes.try_into().unwrap())),
+        MemioScalarType::U32 => serde_json::json!(u32::from_le_bytes(bytes.try_into().unwrap())),
+        MemioScalarType::U64 => serde_json::json!(u64::from_le_bytes(bytes.try_into().unwrap())),
+        MemioScalarType::I8 => serde_json::json!(bytes[0] as i8),
+        MemioScalarType::I16 => serde_json::json!(i16::from_le_bytes(bytes.try_into().unwrap())),
+        MemioScalarType::I32 => serde_json::json!(i32::from_le_bytes(bytes.try_into().unwrap())),
+        MemioScalarType::I64 => serde_json::json!(i64::from_le_bytes(bytes.try_into().unwrap())),
+        MemioScalarType::F32 => serde_json::json!(f32::from_le_bytes(bytes.try_into().unwrap())),
+        MemioScalarType::F64 => serde_json::json!(f64::from_le_bytes(bytes.try_into().unwrap())),
+    }
+}
+
+fn encode_scalar(ty: MemioScalarType, value: &serde_json::Value) -> Result<Vec<u8>, String> {
+    let as_f64 = value
+        .as_f64()
+        .ok_or_else(|| format!("expected a number, got {}", value))?;
+    Ok(match ty {
+        MemioScalarType::U8 => vec![as_f64 as u8],
+        MemioScalarType::U16 => (as_f64 as u16).to_le_bytes().to_vec(),
+        MemioScalarType::U32 => (as_f64 as u32).to_le_bytes().to_vec(),
+        MemioScalarType::U64 => value
+            .as_u64()
+            .ok_or_else(|| format!("expected an integer, got {}", value))?
+            .to_le_bytes()
+            .to_vec(),
+        MemioScalarType::I8 => vec![(as_f64 as i8) as u8],
+        MemioScalarType::I16 => (as_f64 as i16).to_le_bytes().to_vec(),
+        MemioScalarType::I32 => (as_f64 as i32).to_le_bytes().to_vec(),
+        MemioScalarType::I64 => value
+            .as_i64()
+            .ok_or_else(|| format!("expected an integer, got {}", value))?
+            .to_le_bytes()
+            .to_vec(),
+        MemioScalarType::F32 => (as_f64 as f32).to_le_bytes().to_vec(),
+        MemioScalarType::F64 => as_f64.to_le_bytes().to_vec(),
+    })
+}
+
+/// Decodes `bytes` as `ty` into a JSON number (scalar) or array of numbers
+/// (array/tuple). `Struct` fields are out of scope here; callers should fall
+/// back to `memio_read_field` for raw bytes.
+pub fn decode_field(ty: &MemioFieldType, bytes: &[u8]) -> Result<serde_json::Value, String> {
+    match ty {
+        MemioFieldType::Scalar(scalar) => Ok(decode_scalar(*scalar, bytes)),
+        MemioFieldType::Array { elem, len } => {
+            let size = elem.size_bytes();
+            Ok(serde_json::Value::Array(
+                (0..*len)
+                    .map(|i| decode_scalar(*elem, &bytes[i * size..(i + 1) * size]))
+                    .collect(),
+            ))
+        }
+        MemioFieldType::Tuple { elems } => {
+            let mut out = Vec::with_capacity(elems.len());
+            let mut cursor = 0;
+            for elem in elems {
+                let size = elem.size_bytes();
+                out.push(decode_scalar(*elem, &bytes[cursor..cursor + size]));
+                cursor += size;
+            }
+            Ok(serde_json::Value::Array(out))
+        }
+        MemioFieldType::Struct { .. } => Err(
+            "struct-typed fields aren't supported by memio_get_field/memio_set_field; use memio_read_field for raw bytes"
+                .to_string(),
+        ),
+    }
+}
+
+/// Encodes `value` as `ty`'s little-endian byte representation. The inverse
+/// of [`decode_field`].
+pub fn encode_field(ty: &MemioFieldType, value: &serde_json::Value) -> Result<Vec<u8>, String> {
+    match ty {
+        MemioFieldType::Scalar(scalar) => encode_scalar(*scalar, value),
+        MemioFieldType::Array { elem, len } => {
+            let arr = value
+                .as_array()
+                .ok_or_else(|| format!("expected an array of {} {}", len, elem.as_str()))?;
+            if arr.len() != *len {
+                return Err(format!("expected {} elements, got {}", len, arr.len()));
+            }
+            let mut out = Vec::with_capacity(elem.size_bytes() * *len);
+            for v in arr {
+                out.extend(encode_scalar(*elem, v)?);
+            }
+            Ok(out)
+        }
+        MemioFieldType::Tuple { elems } => {
+            let arr = value
+                .as_array()
+                .ok_or_else(|| "expected a tuple array".to_string())?;
+            if arr.len() != elems.len() {
+                return Err(format!(
+                    "expected {} elements, got {}",
+                    elems.len(),
+                    arr.len()
+                ));
+            }
+            let mut out = Vec::new();
+            for (elem, v) in elems.iter().zip(arr) {
+                out.extend(encode_scalar(*elem, v)?);
+            }
+            Ok(out)
+        }
+        MemioFieldType::Struct { .. } => Err(
+            "struct-typed fields aren't supported by memio_get_field/memio_set_field; use memio_write_range for raw bytes"
+                .to_string(),
+        ),
+    }
+}
@@ -0,0 +1,70 @@
+//! Push-based change notifications for subscribed windows.
+//!
+//! Before this module, JS had to poll `memio_read`/`GetVersion` to notice a
+//! write. `memio_subscribe(name)` registers a window's interest instead; from
+//! then on, every successful write to that region emits a `memio://changed`
+//! event (`{name, version, length}`) to each subscribed window, and a window
+//! that subscribes after the fact is immediately caught up with the last
+//! known version instead of waiting for the next write.
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+use tauri::{AppHandle, Emitter, Runtime, WebviewWindow};
+
+pub const CHANGED_EVENT: &str = "memio://changed";
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeNotification {
+    pub name: String,
+    pub version: u64,
+    pub length: usize,
+}
+
+#[derive(Default)]
+struct RegionSubscriptions {
+    windows: HashSet<String>,
+    last: Option<ChangeNotification>,
+}
+
+fn registry() -> &'static Mutex<HashMap<String, RegionSubscriptions>> {
+    static SUBSCRIPTIONS: OnceLock<Mutex<HashMap<String, RegionSubscriptions>>> = OnceLock::new();
+    SUBSCRIPTIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Registers `window` as interested in changes to `name`. If a version has
+/// already been observed for `name`, the window is sent it immediately so a
+/// late subscriber doesn't have to wait for the next write to catch up.
+pub fn subscribe<R: Runtime>(window: &WebviewWindow<R>, name: &str) {
+    let mut reg = registry().lock().unwrap();
+    let entry = reg.entry(name.to_string()).or_default();
+    entry.windows.insert(window.label().to_string());
+    let last = entry.last.clone();
+    drop(reg);
+
+    if let Some(notification) = last {
+        let _ = window.emit(CHANGED_EVENT, notification);
+    }
+}
+
+/// Notifies every window subscribed to `name` that it was written with
+/// `version`/`length`, and remembers the value so later subscribers can be
+/// caught up immediately.
+pub fn notify_changed<R: Runtime>(app: &AppHandle<R>, name: &str, version: u64, length: usize) {
+    let notification = ChangeNotification {
+        name: name.to_string(),
+        version,
+        length,
+    };
+
+    let mut reg = registry().lock().unwrap();
+    let entry = reg.entry(name.to_string()).or_default();
+    entry.last = Some(notification.clone());
+    let windows: Vec<String> = entry.windows.iter().cloned().collect();
+    drop(reg);
+
+    for label in windows {
+        let _ = app.emit_to(label, CHANGED_EVENT, notification.clone());
+    }
+}
@@ -15,10 +15,37 @@
 //! 3. Rust posts buffer to JS with ReadOnly access
 //! 4. JS reads data from the ArrayBuffer
 
-use tauri::{command, Runtime, WebviewWindow};
+use crate::policy::{self, MemioAction};
+use tauri::{command, Emitter, Runtime, WebviewWindow};
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex, OnceLock};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Minimum gap between `*-progress` events emitted by a streaming worker
+/// thread, so a high-rate stream doesn't flood the webview with an event
+/// per chunk.
+const PROGRESS_THROTTLE: Duration = Duration::from_millis(16);
+
+/// Payload for `memio://upload-progress` / `memio://download-progress`.
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamProgressEvent {
+    pub name: String,
+    pub bytes_written: usize,
+    pub total_length: usize,
+    pub chunks_processed: usize,
+}
+
+/// Payload for `memio://upload-error` / `memio://download-error`.
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamErrorEvent {
+    pub name: String,
+    pub offset: usize,
+    pub length: usize,
+    pub error: String,
+}
 
 // ============================================================================
 // Utility Commands
@@ -46,8 +73,102 @@ pub async fn has_shared_buffer(name: String) -> Result<bool, String> {
 // Upload Stream (Control Ring)
 // ============================================================================
 
-const CONTROL_HEADER_SIZE: usize = 16;
-const CONTROL_ENTRY_SIZE: usize = 24;
+/// Byte size of the control ring's header.
+///
+/// Laid out as two 64-byte cache lines so the index each side owns never
+/// shares a line with the other's, avoiding false sharing on every advance:
+///
+/// ```text
+/// offset 0   (line 0): head: u32, capacity: u32, entrySize: u32, padding
+/// offset 64  (line 1): tail: u32, padding
+/// ```
+///
+/// `head` is advanced by whichever side is the ring's consumer (the worker
+/// thread for an upload stream, JS for a download stream); `tail` by
+/// whichever side is the producer. Both `head` and `tail` are published
+/// with a release store after every other write to the slot it guards, and
+/// read with an acquire load before that slot's contents are touched, so a
+/// weakly-ordered reader never observes an index advance before the bytes
+/// it covers — the JS side must mirror this ordering (`Atomics.store` with
+/// a prior fence, `Atomics.load`) to stay correct.
+const CONTROL_HEADER_SIZE: usize = 128;
+const CONTROL_TAIL_OFFSET: usize = 64;
+/// `[buffer_index: u32, length: u32, offset: u64, flags: u32,
+/// uncompressed_length: u32, crc32c: u32, _reserved: u32]`.
+///
+/// `length` is the number of bytes physically sitting in the data
+/// SharedBuffer — the compressed size when [`CTRL_FLAG_COMPRESSED`] is set
+/// in `flags`, otherwise the plain chunk size. `uncompressed_length` and
+/// `crc32c` are only meaningful when the stream was started with
+/// `compress`/`verify_checksums` respectively; a stream that didn't ask for
+/// either leaves them zeroed and unread. `crc32c` always covers the bytes
+/// actually carried in the SharedBuffer, so a torn write or failed inflate
+/// is caught per-chunk before it touches the destination.
+const CONTROL_ENTRY_SIZE: usize = 32;
+const CONTROL_ENTRY_FLAGS_OFFSET: usize = 16;
+const CONTROL_ENTRY_UNCOMPRESSED_LEN_OFFSET: usize = 20;
+const CONTROL_ENTRY_CRC_OFFSET: usize = 24;
+
+/// Set in an entry's `flags` field when this is the chunk that completes
+/// the transfer.
+const CTRL_FLAG_FINALIZE: u32 = 1 << 0;
+/// Set in an entry's `flags` field when `length` bytes in the data
+/// SharedBuffer are DEFLATE-compressed and must be inflated to
+/// `uncompressed_length` bytes before use.
+const CTRL_FLAG_COMPRESSED: u32 = 1 << 1;
+
+/// Acquire-loads the control ring's `head` index (offset 0, line 0).
+unsafe fn ctrl_load_head(ptr: *mut u8) -> u32 {
+    unsafe { std::sync::atomic::AtomicU32::from_ptr(ptr as *mut u32).load(Ordering::Acquire) }
+}
+
+/// Release-stores the control ring's `head` index (offset 0, line 0).
+unsafe fn ctrl_store_head(ptr: *mut u8, value: u32) {
+    unsafe {
+        std::sync::atomic::AtomicU32::from_ptr(ptr as *mut u32).store(value, Ordering::Release);
+    }
+}
+
+/// Acquire-loads the control ring's `tail` index (offset 64, line 1).
+unsafe fn ctrl_load_tail(ptr: *mut u8) -> u32 {
+    unsafe {
+        std::sync::atomic::AtomicU32::from_ptr(ptr.add(CONTROL_TAIL_OFFSET) as *mut u32)
+            .load(Ordering::Acquire)
+    }
+}
+
+/// Release-stores the control ring's `tail` index (offset 64, line 1).
+unsafe fn ctrl_store_tail(ptr: *mut u8, value: u32) {
+    unsafe {
+        std::sync::atomic::AtomicU32::from_ptr(ptr.add(CONTROL_TAIL_OFFSET) as *mut u32)
+            .store(value, Ordering::Release);
+    }
+}
+
+/// Inflates raw-DEFLATE `compressed` bytes into `out`, which must already be
+/// sized to the chunk's `uncompressed_length`. A short or malformed stream
+/// (a torn SharedBuffer write, say) surfaces as an `Err` here rather than a
+/// partially-filled `out`, so the caller can drop just this chunk.
+fn inflate_chunk(compressed: &[u8], out: &mut [u8]) -> std::io::Result<()> {
+    use std::io::Read;
+    flate2::read::DeflateDecoder::new(compressed).read_exact(out)
+}
+
+/// DEFLATE-compresses `data`, falling back to the original bytes when
+/// compression doesn't shrink them — so an already-compressed or
+/// incompressible chunk never costs more than the `flags` bit. Returns the
+/// chosen payload and whether it's compressed.
+fn deflate_chunk(data: &[u8]) -> (Vec<u8>, bool) {
+    use std::io::Write;
+    let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    if encoder.write_all(data).is_ok()
+        && let Ok(compressed) = encoder.finish()
+        && compressed.len() < data.len()
+    {
+        return (compressed, true);
+    }
+    (data.to_vec(), false)
+}
 
 struct UploadSession {
     stop: Arc<AtomicBool>,
@@ -57,6 +178,17 @@ struct UploadSession {
     version: u64,
     total_length: usize,
     capacity: u32,
+    /// `(offset, length)` of every chunk that failed its CRC-32C check,
+    /// populated only when `StartUploadStreamArgs::verify_checksums` is set.
+    checksum_failures: Arc<Mutex<Vec<(usize, usize)>>>,
+    /// Set by the worker thread on the last `write_chunk_from_ptr` failure,
+    /// if any, so `stop_upload_stream` can surface it instead of reporting
+    /// a clean shutdown.
+    last_error: Arc<Mutex<Option<String>>>,
+    /// Signaled by [`notify_upload_stream`] after JS advances `tail`, and by
+    /// `stop_upload_stream` on shutdown, so the worker can block on it
+    /// instead of polling `tail` on a sleep timer.
+    notify: Arc<(Mutex<()>, Condvar)>,
 }
 
 static UPLOAD_SESSIONS: OnceLock<Mutex<HashMap<String, UploadSession>>> = OnceLock::new();
@@ -73,6 +205,19 @@ pub struct StartUploadStreamArgs {
     pub chunk_size: usize,
     pub buffer_count: u32,
     pub version: u64,
+    /// When set, JS must compute a CRC-32C of each chunk's bytes before
+    /// advancing `tail` and store it in the entry's trailing 4 bytes
+    /// (offset [`CONTROL_ENTRY_CRC_OFFSET`]); the worker then recomputes it
+    /// over the same slice and drops the chunk on mismatch instead of
+    /// writing possibly-torn bytes into the memio region. Off by default
+    /// since it costs a pass over every chunk.
+    #[serde(default)]
+    pub verify_checksums: bool,
+    /// When set, JS may DEFLATE-compress a chunk before writing it and flag
+    /// the entry accordingly (see [`CTRL_FLAG_COMPRESSED`]); the worker
+    /// inflates it before writing to the memio region. Off by default.
+    #[serde(default)]
+    pub compress: bool,
 }
 
 #[derive(serde::Serialize)]
@@ -155,9 +300,12 @@ pub async fn start_upload_stream<R: Runtime>(
                 .map_err(|e| format!("Failed to create control buffer: {:?}", e))?;
 
                 // Initialize control header [head, tail, capacity, entry_size]
+                // `head` (offset 0) and `tail` (offset CONTROL_TAIL_OFFSET)
+                // default to zero from the zeroed array; only the read-only
+                // capacity/entrySize fields in line 0 need initializing.
                 let mut header = [0u8; CONTROL_HEADER_SIZE];
-                header[8..12].copy_from_slice(&capacity_clone.to_le_bytes());
-                header[12..16].copy_from_slice(&(CONTROL_ENTRY_SIZE as u32).to_le_bytes());
+                header[4..8].copy_from_slice(&capacity_clone.to_le_bytes());
+                header[8..12].copy_from_slice(&(CONTROL_ENTRY_SIZE as u32).to_le_bytes());
                 crate::windows_shared_buffer::write_to_buffer(&control_name_clone, 0, &header)
                     .map_err(|e| format!("Failed to init control header: {e}"))?;
 
@@ -240,9 +388,21 @@ pub async fn start_upload_stream<R: Runtime>(
     let version = args.version;
     let total_length = args.total_length;
     let capacity_usize = capacity as usize;
+    let verify_checksums = args.verify_checksums;
+    let compress = args.compress;
+    let checksum_failures = Arc::new(Mutex::new(Vec::new()));
+    let checksum_failures_thread = Arc::clone(&checksum_failures);
+    let last_error = Arc::new(Mutex::new(None));
+    let last_error_thread = Arc::clone(&last_error);
+    let app_handle = window.app_handle().clone();
+    let notify = Arc::new((Mutex::new(()), Condvar::new()));
+    let notify_thread = Arc::clone(&notify);
 
     let handle = std::thread::spawn(move || {
         let stop_flag = stop_flag_thread;
+        let mut bytes_written: usize = 0;
+        let mut chunks_processed: usize = 0;
+        let mut last_progress_emit = Instant::now() - PROGRESS_THROTTLE;
         let mut data_ptrs: Vec<(*mut u8, u64)> = Vec::new();
         for buffer_name in buffer_names_thread.iter() {
             if let Ok(ptr) = crate::windows_shared_buffer::get_buffer_ptr(buffer_name) {
@@ -273,14 +433,24 @@ pub async fn start_upload_stream<R: Runtime>(
         }
 
         loop {
-            let head = unsafe { read_u32(ctrl_ptr, 0) };
-            let tail = unsafe { read_u32(ctrl_ptr, 4) };
+            // `tail` is JS's publish point: an acquire load here pairs with
+            // the release store JS does after writing the entry body, so
+            // the reads of `buffer_index`/`length`/`offset`/`finalize` below
+            // never race the writes that produced them.
+            let head = unsafe { ctrl_load_head(ctrl_ptr) };
+            let tail = unsafe { ctrl_load_tail(ctrl_ptr) };
 
             if head == tail {
                 if stop_flag.load(Ordering::Relaxed) {
                     break;
                 }
-                std::thread::sleep(std::time::Duration::from_millis(1));
+                // Waits for `notify_upload_stream` (or `stop_upload_stream`)
+                // to signal, falling back to a bounded wait so a missed
+                // notification — e.g. JS notifying before this thread
+                // reached the wait — can't stall the stream indefinitely.
+                let (lock, cvar) = &*notify_thread;
+                let guard = lock.lock().unwrap();
+                let _ = cvar.wait_timeout(guard, Duration::from_millis(1)).unwrap();
                 continue;
             }
 
@@ -289,28 +459,99 @@ pub async fn start_upload_stream<R: Runtime>(
             let buffer_index = unsafe { read_u32(ctrl_ptr, entry_offset) } as usize;
             let length = unsafe { read_u32(ctrl_ptr, entry_offset + 4) } as usize;
             let offset = unsafe { read_u64(ctrl_ptr, entry_offset + 8) } as usize;
-            let finalize = unsafe { read_u32(ctrl_ptr, entry_offset + 16) } != 0;
+            let flags = unsafe { read_u32(ctrl_ptr, entry_offset + CONTROL_ENTRY_FLAGS_OFFSET) };
+            let finalize = flags & CTRL_FLAG_FINALIZE != 0;
+            let compressed = compress && flags & CTRL_FLAG_COMPRESSED != 0;
+            let uncompressed_length = unsafe {
+                read_u32(ctrl_ptr, entry_offset + CONTROL_ENTRY_UNCOMPRESSED_LEN_OFFSET)
+            } as usize;
 
             if buffer_index < data_ptrs.len() {
                 let (data_ptr, data_size) = data_ptrs[buffer_index];
-                if length <= data_size as usize {
+                let checksum_ok = !verify_checksums || length == 0 || unsafe {
+                    let expected = read_u32(ctrl_ptr, entry_offset + CONTROL_ENTRY_CRC_OFFSET);
+                    let chunk = std::slice::from_raw_parts(data_ptr, length);
+                    memio_core::crc32c(chunk) == expected
+                };
+
+                if !checksum_ok {
+                    checksum_failures_thread.lock().unwrap().push((offset, length));
+                    tracing::warn!(
+                        "[MemioWindows] Upload chunk failed CRC-32C check, dropped: offset {} length {}",
+                        offset,
+                        length
+                    );
+                } else if length <= data_size as usize {
                     let final_info = if finalize {
                         Some((version, total_length))
                     } else {
                         None
                     };
-                    let _ = memio_platform::windows::write_chunk_from_ptr(
-                        &name_thread,
-                        data_ptr,
-                        offset,
-                        length,
-                        final_info,
-                    );
+
+                    let mut scratch: Vec<u8>;
+                    let write_result = if compressed {
+                        scratch = vec![0u8; uncompressed_length];
+                        let chunk = unsafe { std::slice::from_raw_parts(data_ptr, length) };
+                        match inflate_chunk(chunk, &mut scratch) {
+                            Ok(()) => memio_platform::windows::write_chunk_from_ptr(
+                                &name_thread,
+                                scratch.as_mut_ptr(),
+                                offset,
+                                scratch.len(),
+                                final_info,
+                            ),
+                            Err(err) => Err(err.to_string()),
+                        }
+                    } else {
+                        memio_platform::windows::write_chunk_from_ptr(
+                            &name_thread,
+                            data_ptr,
+                            offset,
+                            length,
+                            final_info,
+                        )
+                        .map_err(|err| err.to_string())
+                    };
+
+                    match write_result {
+                        Ok(()) => {
+                            bytes_written += if compressed { uncompressed_length } else { length };
+                            chunks_processed += 1;
+
+                            if last_progress_emit.elapsed() >= PROGRESS_THROTTLE {
+                                last_progress_emit = Instant::now();
+                                let _ = app_handle.emit(
+                                    "memio://upload-progress",
+                                    StreamProgressEvent {
+                                        name: name_thread.clone(),
+                                        bytes_written,
+                                        total_length,
+                                        chunks_processed,
+                                    },
+                                );
+                            }
+                        }
+                        Err(message) => {
+                            *last_error_thread.lock().unwrap() = Some(message.clone());
+                            let _ = app_handle.emit(
+                                "memio://upload-error",
+                                StreamErrorEvent {
+                                    name: name_thread.clone(),
+                                    offset,
+                                    length,
+                                    error: message,
+                                },
+                            );
+                        }
+                    }
                 }
             }
 
+            // Release-store the consumed index only after the chunk is
+            // fully read, so JS never reuses the slot for a fresh entry
+            // while this thread is still acting on the old one.
             let next_head = head.wrapping_add(1);
-            unsafe { write_u32(ctrl_ptr, 0, next_head) };
+            unsafe { ctrl_store_head(ctrl_ptr, next_head) };
         }
     });
 
@@ -325,13 +566,33 @@ pub async fn start_upload_stream<R: Runtime>(
             version: args.version,
             total_length: args.total_length,
             capacity,
+            checksum_failures,
+            last_error,
+            notify,
         },
     );
 
     Ok(response)
 }
 
-/// Stops a ring-based upload stream and releases SharedBuffers.
+/// Wakes an upload stream's worker thread after JS advances `tail`, so it
+/// can pick up the new entry without waiting out its fallback poll
+/// interval. A no-op (aside from the lookup) if the stream isn't running.
+#[command]
+pub async fn notify_upload_stream(name: String) -> Result<(), String> {
+    let sessions = upload_sessions().lock().unwrap();
+    let session = sessions
+        .get(&name)
+        .ok_or_else(|| format!("Upload stream '{}' not found", name))?;
+    let (lock, cvar) = &*session.notify;
+    let _guard = lock.lock().unwrap();
+    cvar.notify_one();
+    Ok(())
+}
+
+/// Stops a ring-based upload stream and releases SharedBuffers. Returns the
+/// formatted error from the last failed `write_chunk_from_ptr` call, if the
+/// worker ever hit one, instead of reporting a clean shutdown.
 #[command]
 pub async fn stop_upload_stream(name: String) -> Result<(), String> {
     let mut session = {
@@ -342,38 +603,96 @@ pub async fn stop_upload_stream(name: String) -> Result<(), String> {
     };
 
     session.stop.store(true, Ordering::Relaxed);
+    {
+        let (lock, cvar) = &*session.notify;
+        let _guard = lock.lock().unwrap();
+        cvar.notify_one();
+    }
     if let Some(handle) = session.handle.take() {
         let _ = handle.join();
     }
 
+    let failures = session.checksum_failures.lock().unwrap();
+    if !failures.is_empty() {
+        tracing::warn!(
+            "[MemioWindows] Upload stream '{}' dropped {} chunk(s) that failed CRC-32C verification",
+            name,
+            failures.len()
+        );
+    }
+    drop(failures);
+
     let _ = crate::windows_shared_buffer::close_buffer(&session.control_name);
     for buffer_name in session.buffer_names.iter() {
         let _ = crate::windows_shared_buffer::close_buffer(buffer_name);
     }
 
-    Ok(())
+    match session.last_error.lock().unwrap().take() {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
 }
 
 // ============================================================================
-// Direct Upload (Front → Back)
+// Download Stream (Control Ring, producer/consumer reversed)
 // ============================================================================
 
-/// Response from prepare_upload_buffer
-#[derive(serde::Serialize)]
-pub struct PrepareBufferResponse {
+struct DownloadSession {
+    stop: Arc<AtomicBool>,
+    handle: Option<std::thread::JoinHandle<()>>,
+    control_name: String,
+    buffer_names: Vec<String>,
+    /// Set by the worker thread on the last read failure, if any, so
+    /// `stop_download_stream` can surface it instead of reporting a clean
+    /// shutdown — mirrors `UploadSession::last_error`.
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+static DOWNLOAD_SESSIONS: OnceLock<Mutex<HashMap<String, DownloadSession>>> = OnceLock::new();
+
+fn download_sessions() -> &'static Mutex<HashMap<String, DownloadSession>> {
+    DOWNLOAD_SESSIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartDownloadStreamArgs {
     pub name: String,
-    pub size: u64,
-    pub ready: bool,
+    pub chunk_size: usize,
+    pub buffer_count: u32,
+    /// When set, the worker DEFLATE-compresses each chunk before writing it
+    /// to the data SharedBuffer and flags the entry accordingly, falling
+    /// back to storing it uncompressed when compression doesn't shrink it.
+    /// Off by default.
+    #[serde(default)]
+    pub compress: bool,
 }
 
-/// Prepare a SharedBuffer for upload.
-/// Creates a WebView2 SharedBuffer and posts it to JS.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartDownloadStreamResponse {
+    pub control_name: String,
+    pub buffer_names: Vec<String>,
+    pub capacity: u32,
+    pub entry_size: u32,
+    pub version: u64,
+    pub total_length: usize,
+}
+
+/// Starts a ring-based download stream, the mirror image of
+/// `start_upload_stream` with the producer/consumer roles swapped: a worker
+/// thread fills each data SharedBuffer in turn from the memio region named
+/// `args.name`, publishes a `[buffer_index, length, offset, finalize]`
+/// control entry at `tail`, and advances `tail`; JS drains entries starting
+/// at `head`, reads the corresponding ArrayBuffer, and advances `head` once
+/// done with it. This lets the frontend consume a multi-gigabyte region
+/// chunk-by-chunk without either side ever materializing the whole payload,
+/// the same way `start_upload_stream` avoids it on the way in.
 #[command]
-pub async fn prepare_upload_buffer<R: Runtime>(
+pub async fn start_download_stream<R: Runtime>(
     window: WebviewWindow<R>,
-    name: String,
-    size: u64,
-) -> Result<PrepareBufferResponse, String> {
+    args: StartDownloadStreamArgs,
+) -> Result<StartDownloadStreamResponse, String> {
     use std::sync::mpsc;
     use webview2_com::Microsoft::Web::WebView2::Win32::{
         ICoreWebView2Environment12, ICoreWebView2_17, ICoreWebView2_2,
@@ -382,17 +701,34 @@ pub async fn prepare_upload_buffer<R: Runtime>(
     use windows::core::PCWSTR;
     use windows_core::Interface;
 
-    tracing::info!(
-        "[MemioWindows] prepare_upload_buffer: '{}' ({} bytes)",
-        name,
-        size
-    );
+    if args.buffer_count == 0 {
+        return Err("buffer_count must be > 0".to_string());
+    }
 
-    let (tx, rx) = mpsc::channel::<Result<PrepareBufferResponse, String>>();
-    let name_clone = name.clone();
+    let (version, total_length) = memio_platform::windows::read_shared_info(&args.name)?;
+
+    let control_name = format!("{}__dl_ctrl", args.name);
+    let buffer_names: Vec<String> = (0..args.buffer_count)
+        .map(|i| format!("{}__dl_data_{}", args.name, i))
+        .collect();
+    let capacity = args.buffer_count;
+    let control_size = CONTROL_HEADER_SIZE + (capacity as usize * CONTROL_ENTRY_SIZE);
+
+    {
+        let sessions = download_sessions().lock().unwrap();
+        if sessions.contains_key(&args.name) {
+            return Err(format!("Download stream '{}' already started", args.name));
+        }
+    }
+
+    let (tx, rx) = mpsc::channel::<Result<StartDownloadStreamResponse, String>>();
+    let control_name_clone = control_name.clone();
+    let buffer_names_clone = buffer_names.clone();
+    let chunk_size = args.chunk_size;
+    let capacity_clone = capacity;
 
     let webview_result = window.with_webview(move |webview| {
-        let result: Result<PrepareBufferResponse, String> = (|| {
+        let result: Result<StartDownloadStreamResponse, String> = (|| {
             let controller = webview.controller();
             unsafe {
                 let core_webview = controller
@@ -411,47 +747,88 @@ pub async fn prepare_upload_buffer<R: Runtime>(
                     .cast()
                     .map_err(|e| format!("Failed to cast to Environment12: {:?}", e))?;
 
-                let (ptr, actual_size) =
-                    crate::windows_shared_buffer::create_shared_buffer(&env12, &name_clone, size)
-                        .map_err(|e| format!("Failed to create SharedBuffer: {:?}", e))?;
-
                 let webview17: ICoreWebView2_17 = core_webview
                     .cast()
                     .map_err(|e| format!("Failed to cast to WebView2_17: {:?}", e))?;
 
-                let metadata = format!(
-                    r#"{{"name":"{}","size":{},"forUpload":true}}"#,
-                    name_clone, actual_size
-                );
-                let wide: Vec<u16> = metadata.encode_utf16().chain(std::iter::once(0)).collect();
+                // Control buffer. READ_WRITE because JS is the consumer here
+                // and must be able to advance `head` after draining an entry.
+                let (_ctrl_ptr, _ctrl_size) = crate::windows_shared_buffer::create_shared_buffer(
+                    &env12,
+                    &control_name_clone,
+                    control_size as u64,
+                )
+                .map_err(|e| format!("Failed to create control buffer: {:?}", e))?;
 
+                // `head` (offset 0) and `tail` (offset CONTROL_TAIL_OFFSET)
+                // default to zero from the zeroed array; only the read-only
+                // capacity/entrySize fields in line 0 need initializing.
+                let mut header = [0u8; CONTROL_HEADER_SIZE];
+                header[4..8].copy_from_slice(&capacity_clone.to_le_bytes());
+                header[8..12].copy_from_slice(&(CONTROL_ENTRY_SIZE as u32).to_le_bytes());
+                crate::windows_shared_buffer::write_to_buffer(&control_name_clone, 0, &header)
+                    .map_err(|e| format!("Failed to init control header: {e}"))?;
+
+                let ctrl_metadata = format!(
+                    r#"{{"name":"{}","size":{},"forDownloadControl":true,"capacity":{},"entrySize":{}}}"#,
+                    control_name_clone, control_size, capacity_clone, CONTROL_ENTRY_SIZE
+                );
+                let ctrl_wide: Vec<u16> =
+                    ctrl_metadata.encode_utf16().chain(std::iter::once(0)).collect();
                 {
                     let registry = crate::windows_shared_buffer::get_registry_internal();
                     let reg = registry.lock().unwrap();
-                    if let Some(entry) = reg.get(&name_clone) {
-                        webview17
-                            .PostSharedBufferToScript(
-                                &entry.buffer,
-                                COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_WRITE,
-                                PCWSTR::from_raw(wide.as_ptr()),
-                            )
-                            .map_err(|e| format!("Failed to post SharedBuffer: {:?}", e))?;
-                    } else {
-                        return Err(format!("Buffer '{}' not found in registry", name_clone));
-                    }
+                    let entry = reg
+                        .get(&control_name_clone)
+                        .ok_or_else(|| format!("Control buffer '{}' not found", control_name_clone))?;
+                    webview17
+                        .PostSharedBufferToScript(
+                            &entry.buffer,
+                            COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_WRITE,
+                            PCWSTR::from_raw(ctrl_wide.as_ptr()),
+                        )
+                        .map_err(|e| format!("Failed to post control buffer: {:?}", e))?;
                 }
 
-                tracing::info!(
-                    "[MemioWindows] Upload buffer posted: '{}' ({} bytes at {:?})",
-                    name_clone,
-                    actual_size,
-                    ptr
-                );
+                // Data buffers. READ_WRITE isn't needed by JS (it only
+                // reads), but the worker writes into them from Rust before
+                // each entry is published, same access level `start_upload_stream`
+                // grants its data buffers.
+                for buffer_name in buffer_names_clone.iter() {
+                    let (_ptr, _actual_size) = crate::windows_shared_buffer::create_shared_buffer(
+                        &env12,
+                        buffer_name,
+                        chunk_size as u64,
+                    )
+                    .map_err(|e| format!("Failed to create data buffer: {:?}", e))?;
 
-                Ok(PrepareBufferResponse {
-                    name: name_clone.clone(),
-                    size: actual_size,
-                    ready: true,
+                    let metadata = format!(
+                        r#"{{"name":"{}","size":{},"forDownload":true}}"#,
+                        buffer_name, chunk_size
+                    );
+                    let wide: Vec<u16> =
+                        metadata.encode_utf16().chain(std::iter::once(0)).collect();
+                    let registry = crate::windows_shared_buffer::get_registry_internal();
+                    let reg = registry.lock().unwrap();
+                    let entry = reg
+                        .get(buffer_name)
+                        .ok_or_else(|| format!("Buffer '{}' not found in registry", buffer_name))?;
+                    webview17
+                        .PostSharedBufferToScript(
+                            &entry.buffer,
+                            COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_WRITE,
+                            PCWSTR::from_raw(wide.as_ptr()),
+                        )
+                        .map_err(|e| format!("Failed to post data buffer: {:?}", e))?;
+                }
+
+                Ok(StartDownloadStreamResponse {
+                    control_name: control_name_clone.clone(),
+                    buffer_names: buffer_names_clone.clone(),
+                    capacity: capacity_clone,
+                    entry_size: CONTROL_ENTRY_SIZE as u32,
+                    version,
+                    total_length,
                 })
             }
         })();
@@ -463,43 +840,372 @@ pub async fn prepare_upload_buffer<R: Runtime>(
         return Err(format!("with_webview failed: {:?}", e));
     }
 
-    rx.recv()
-        .map_err(|e| format!("Failed to receive result: {:?}", e))?
-}
+    let response = rx
+        .recv()
+        .map_err(|e| format!("Failed to receive result: {:?}", e))??;
 
-#[derive(serde::Deserialize)]
-pub struct CommitUploadArgs {
-    pub name: String,
-    pub version: u64,
-    pub length: usize,
-    pub offset: Option<usize>,
-    #[serde(alias = "bufferName")]
-    pub buffer_name: Option<String>,
-    #[serde(alias = "totalLength")]
-    pub total_length: Option<usize>,
-    pub finalize: Option<bool>,
-}
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let stop_flag_thread = Arc::clone(&stop_flag);
+    let buffer_names_thread = buffer_names.clone();
+    let control_name_thread = control_name.clone();
+    let name_thread = args.name.clone();
+    let capacity_usize = capacity as usize;
+    let compress = args.compress;
+    let last_error = Arc::new(Mutex::new(None));
+    let last_error_thread = Arc::clone(&last_error);
+    let app_handle = window.app_handle().clone();
 
-/// Commit an upload - read data from SharedBuffer and write to memio region.
-/// If offset is provided, writes a chunk and optionally finalizes the header.
-#[command]
-pub async fn commit_upload_buffer(args: CommitUploadArgs) -> Result<(), String> {
-    let shared_name = args.buffer_name.as_deref().unwrap_or(&args.name);
-    let (ptr, size) = crate::windows_shared_buffer::get_buffer_ptr(shared_name)?;
-    if args.length > size as usize {
-        return Err(format!(
-            "Requested length {} exceeds SharedBuffer size {} for '{}'",
-            args.length, size, args.name
-        ));
-    }
+    let handle = std::thread::spawn(move || {
+        let stop_flag = stop_flag_thread;
+        let mut bytes_written: usize = 0;
+        let mut chunks_processed: usize = 0;
+        let mut last_progress_emit = Instant::now() - PROGRESS_THROTTLE;
+        let mut data_ptrs: Vec<(*mut u8, u64)> = Vec::new();
+        for buffer_name in buffer_names_thread.iter() {
+            if let Ok(ptr) = crate::windows_shared_buffer::get_buffer_ptr(buffer_name) {
+                data_ptrs.push(ptr);
+            } else {
+                return;
+            }
+        }
+        let (ctrl_ptr, _ctrl_size) =
+            match crate::windows_shared_buffer::get_buffer_ptr(&control_name_thread) {
+                Ok(ptr) => ptr,
+                Err(_) => return,
+            };
 
-    if let Some(offset) = args.offset {
-        let finalize = args.finalize.unwrap_or(false);
-        let final_info = if finalize {
-            Some((
-                args.version,
-                args.total_length
-                    .ok_or_else(|| "totalLength is required to finalize")?,
+        unsafe fn read_u32(ptr: *mut u8, offset: usize) -> u32 {
+            let mut bytes = [0u8; 4];
+            std::ptr::copy_nonoverlapping(ptr.add(offset), bytes.as_mut_ptr(), 4);
+            u32::from_le_bytes(bytes)
+        }
+        unsafe fn write_u32(ptr: *mut u8, offset: usize, value: u32) {
+            let bytes = value.to_le_bytes();
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.add(offset), 4);
+        }
+        unsafe fn write_u64(ptr: *mut u8, offset: usize, value: u64) {
+            let bytes = value.to_le_bytes();
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.add(offset), 8);
+        }
+
+        let mut read_offset: usize = 0;
+        let mut finalize_sent = false;
+
+        loop {
+            if finalize_sent {
+                if stop_flag.load(Ordering::Relaxed) {
+                    break;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                continue;
+            }
+
+            // Here Rust is the producer: `head` is JS's consume point, so
+            // an acquire load is what lets this thread safely reuse a slot
+            // once it sees JS has advanced past it.
+            let head = unsafe { ctrl_load_head(ctrl_ptr) };
+            let tail = unsafe { ctrl_load_tail(ctrl_ptr) };
+            let used = tail.wrapping_sub(head) as usize;
+
+            if used >= capacity_usize {
+                // Every data buffer is still outstanding with JS; wait for
+                // it to drain one by advancing `head`.
+                if stop_flag.load(Ordering::Relaxed) {
+                    break;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                continue;
+            }
+
+            let length = chunk_size.min(total_length - read_offset);
+            let index = (tail as usize) % capacity_usize;
+            let (data_ptr, data_size) = data_ptrs[index];
+            let mut stored_length = length;
+            let mut compressed_flag = false;
+
+            if length > 0 && length <= data_size as usize {
+                let copy_result = if compress {
+                    memio_platform::windows::read_shared_range(&name_thread, read_offset, length)
+                        .map(|raw| {
+                            let (payload, was_compressed) = deflate_chunk(&raw);
+                            compressed_flag = was_compressed;
+                            stored_length = payload.len();
+                            unsafe {
+                                std::ptr::copy_nonoverlapping(
+                                    payload.as_ptr(),
+                                    data_ptr,
+                                    payload.len(),
+                                );
+                            }
+                        })
+                        .map_err(|err| err.to_string())
+                } else {
+                    memio_platform::windows::copy_shared_range_to_ptr(
+                        &name_thread,
+                        data_ptr,
+                        read_offset,
+                        length,
+                    )
+                    .map_err(|err| err.to_string())
+                };
+
+                match copy_result {
+                    Ok(()) => {
+                        bytes_written += length;
+                        chunks_processed += 1;
+
+                        if last_progress_emit.elapsed() >= PROGRESS_THROTTLE {
+                            last_progress_emit = Instant::now();
+                            let _ = app_handle.emit(
+                                "memio://download-progress",
+                                StreamProgressEvent {
+                                    name: name_thread.clone(),
+                                    bytes_written,
+                                    total_length,
+                                    chunks_processed,
+                                },
+                            );
+                        }
+                    }
+                    Err(message) => {
+                        *last_error_thread.lock().unwrap() = Some(message.clone());
+                        let _ = app_handle.emit(
+                            "memio://download-error",
+                            StreamErrorEvent {
+                                name: name_thread.clone(),
+                                offset: read_offset,
+                                length,
+                                error: message,
+                            },
+                        );
+                    }
+                }
+            }
+
+            let finalize = read_offset + length >= total_length;
+            let mut flags = if finalize { CTRL_FLAG_FINALIZE } else { 0 };
+            if compressed_flag {
+                flags |= CTRL_FLAG_COMPRESSED;
+            }
+
+            let entry_offset = CONTROL_HEADER_SIZE + index * CONTROL_ENTRY_SIZE;
+            unsafe {
+                write_u32(ctrl_ptr, entry_offset, index as u32);
+                write_u32(ctrl_ptr, entry_offset + 4, stored_length as u32);
+                write_u64(ctrl_ptr, entry_offset + 8, read_offset as u64);
+                write_u32(ctrl_ptr, entry_offset + CONTROL_ENTRY_FLAGS_OFFSET, flags);
+                write_u32(
+                    ctrl_ptr,
+                    entry_offset + CONTROL_ENTRY_UNCOMPRESSED_LEN_OFFSET,
+                    if compressed_flag { length as u32 } else { 0 },
+                );
+            }
+
+            read_offset += length;
+            // Release-store `tail` only after every entry field above is
+            // written, so JS's acquire load of `tail` never observes a
+            // slot whose body isn't fully published yet.
+            let next_tail = tail.wrapping_add(1);
+            unsafe { ctrl_store_tail(ctrl_ptr, next_tail) };
+
+            if finalize {
+                finalize_sent = true;
+            }
+        }
+    });
+
+    let mut sessions = download_sessions().lock().unwrap();
+    sessions.insert(
+        args.name,
+        DownloadSession {
+            stop: stop_flag,
+            handle: Some(handle),
+            control_name,
+            buffer_names,
+            last_error,
+        },
+    );
+
+    Ok(response)
+}
+
+/// Stops a ring-based download stream and releases SharedBuffers. Returns
+/// the formatted error from the last failed `copy_shared_range_to_ptr`
+/// call, if the worker ever hit one, instead of reporting a clean shutdown.
+#[command]
+pub async fn stop_download_stream(name: String) -> Result<(), String> {
+    let mut session = {
+        let mut sessions = download_sessions().lock().unwrap();
+        sessions
+            .remove(&name)
+            .ok_or_else(|| format!("Download stream '{}' not found", name))?
+    };
+
+    session.stop.store(true, Ordering::Relaxed);
+    if let Some(handle) = session.handle.take() {
+        let _ = handle.join();
+    }
+
+    let _ = crate::windows_shared_buffer::close_buffer(&session.control_name);
+    for buffer_name in session.buffer_names.iter() {
+        let _ = crate::windows_shared_buffer::close_buffer(buffer_name);
+    }
+
+    match session.last_error.lock().unwrap().take() {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+// ============================================================================
+// Direct Upload (Front → Back)
+// ============================================================================
+
+/// Response from prepare_upload_buffer
+#[derive(serde::Serialize)]
+pub struct PrepareBufferResponse {
+    pub name: String,
+    pub size: u64,
+    pub ready: bool,
+}
+
+/// Prepare a SharedBuffer for upload.
+/// Creates a WebView2 SharedBuffer and posts it to JS.
+#[command]
+pub async fn prepare_upload_buffer<R: Runtime>(
+    window: WebviewWindow<R>,
+    name: String,
+    size: u64,
+) -> Result<PrepareBufferResponse, String> {
+    use std::sync::mpsc;
+    use webview2_com::Microsoft::Web::WebView2::Win32::{
+        ICoreWebView2Environment12, ICoreWebView2_17, ICoreWebView2_2,
+        COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_WRITE,
+    };
+    use windows::core::PCWSTR;
+    use windows_core::Interface;
+
+    tracing::info!(
+        "[MemioWindows] prepare_upload_buffer: '{}' ({} bytes)",
+        name,
+        size
+    );
+
+    let (tx, rx) = mpsc::channel::<Result<PrepareBufferResponse, String>>();
+    let name_clone = name.clone();
+
+    let webview_result = window.with_webview(move |webview| {
+        let result: Result<PrepareBufferResponse, String> = (|| {
+            let controller = webview.controller();
+            unsafe {
+                let core_webview = controller
+                    .CoreWebView2()
+                    .map_err(|e| format!("Failed to get CoreWebView2: {:?}", e))?;
+
+                let webview2: ICoreWebView2_2 = core_webview
+                    .cast()
+                    .map_err(|e| format!("Failed to cast to WebView2_2: {:?}", e))?;
+
+                let env = webview2
+                    .Environment()
+                    .map_err(|e| format!("Failed to get Environment: {:?}", e))?;
+
+                let env12: ICoreWebView2Environment12 = env
+                    .cast()
+                    .map_err(|e| format!("Failed to cast to Environment12: {:?}", e))?;
+
+                let (ptr, actual_size) =
+                    crate::windows_shared_buffer::create_shared_buffer(&env12, &name_clone, size)
+                        .map_err(|e| format!("Failed to create SharedBuffer: {:?}", e))?;
+
+                let webview17: ICoreWebView2_17 = core_webview
+                    .cast()
+                    .map_err(|e| format!("Failed to cast to WebView2_17: {:?}", e))?;
+
+                let metadata = format!(
+                    r#"{{"name":"{}","size":{},"forUpload":true}}"#,
+                    name_clone, actual_size
+                );
+                let wide: Vec<u16> = metadata.encode_utf16().chain(std::iter::once(0)).collect();
+
+                {
+                    let registry = crate::windows_shared_buffer::get_registry_internal();
+                    let reg = registry.lock().unwrap();
+                    if let Some(entry) = reg.get(&name_clone) {
+                        webview17
+                            .PostSharedBufferToScript(
+                                &entry.buffer,
+                                COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_WRITE,
+                                PCWSTR::from_raw(wide.as_ptr()),
+                            )
+                            .map_err(|e| format!("Failed to post SharedBuffer: {:?}", e))?;
+                    } else {
+                        return Err(format!("Buffer '{}' not found in registry", name_clone));
+                    }
+                }
+
+                tracing::info!(
+                    "[MemioWindows] Upload buffer posted: '{}' ({} bytes at {:?})",
+                    name_clone,
+                    actual_size,
+                    ptr
+                );
+
+                Ok(PrepareBufferResponse {
+                    name: name_clone.clone(),
+                    size: actual_size,
+                    ready: true,
+                })
+            }
+        })();
+
+        let _ = tx.send(result);
+    });
+
+    if let Err(e) = webview_result {
+        return Err(format!("with_webview failed: {:?}", e));
+    }
+
+    rx.recv()
+        .map_err(|e| format!("Failed to receive result: {:?}", e))?
+}
+
+#[derive(serde::Deserialize)]
+pub struct CommitUploadArgs {
+    pub name: String,
+    pub version: u64,
+    pub length: usize,
+    pub offset: Option<usize>,
+    #[serde(alias = "bufferName")]
+    pub buffer_name: Option<String>,
+    #[serde(alias = "totalLength")]
+    pub total_length: Option<usize>,
+    pub finalize: Option<bool>,
+}
+
+/// Commit an upload - read data from SharedBuffer and write to memio region.
+/// If offset is provided, writes a chunk and optionally finalizes the header.
+#[command]
+pub async fn commit_upload_buffer<R: Runtime>(
+    window: WebviewWindow<R>,
+    args: CommitUploadArgs,
+) -> Result<(), String> {
+    let shared_name = args.buffer_name.as_deref().unwrap_or(&args.name);
+    let (ptr, size) = crate::windows_shared_buffer::get_buffer_ptr(shared_name)?;
+    if args.length > size as usize {
+        return Err(format!(
+            "Requested length {} exceeds SharedBuffer size {} for '{}'",
+            args.length, size, args.name
+        ));
+    }
+
+    if let Some(offset) = args.offset {
+        let finalize = args.finalize.unwrap_or(false);
+        let final_info = if finalize {
+            Some((
+                args.version,
+                args.total_length
+                    .ok_or_else(|| "totalLength is required to finalize")?,
             ))
         } else {
             None
@@ -521,6 +1227,17 @@ pub async fn commit_upload_buffer(args: CommitUploadArgs) -> Result<(), String>
             finalize
         );
 
+        if finalize {
+            if let Some((version, total_length)) = final_info {
+                crate::subscriptions::notify_changed(
+                    window.app_handle(),
+                    &args.name,
+                    version,
+                    total_length,
+                );
+            }
+        }
+
         return Ok(());
     }
 
@@ -535,6 +1252,8 @@ pub async fn commit_upload_buffer(args: CommitUploadArgs) -> Result<(), String>
 
     memio_platform::windows::write_to_shared(&args.name, args.version, data_slice)?;
 
+    crate::subscriptions::notify_changed(window.app_handle(), &args.name, args.version, data_slice.len());
+
     Ok(())
 }
 
@@ -549,6 +1268,11 @@ pub struct DownloadBufferResponse {
     pub version: u64,
     pub size: usize,
     pub posted: bool,
+    /// `http://127.0.0.1:<port>/<name>` to `fetch`/`<img src>` the blob
+    /// directly, set only when a [`crate::MemioBlobServerBuilder`] has been
+    /// installed; `None` when the SharedBuffer injection path (the default)
+    /// served the blob instead.
+    pub url: Option<String>,
 }
 
 /// Send data via SharedBuffer (back→front).
@@ -576,6 +1300,18 @@ pub async fn send_download_buffer<R: Runtime>(
         data_len
     );
 
+    if crate::windows_blob_server::is_enabled() {
+        let (_version, data) = memio_platform::windows::read_from_shared(&name)?;
+        let url = crate::windows_blob_server::register_blob(&name, data)?;
+        return Ok(DownloadBufferResponse {
+            name,
+            version,
+            size: data_len,
+            posted: true,
+            url: Some(url),
+        });
+    }
+
     let (tx, rx) = mpsc::channel::<Result<DownloadBufferResponse, String>>();
     let name_clone = name.clone();
 
@@ -639,6 +1375,124 @@ pub async fn send_download_buffer<R: Runtime>(
                     version,
                     size: data_len,
                     posted: true,
+                    url: None,
+                })
+            }
+        })();
+
+        let _ = tx.send(result);
+    });
+
+    if let Err(e) = webview_result {
+        return Err(format!("with_webview failed: {:?}", e));
+    }
+
+    rx.recv()
+        .map_err(|e| format!("Failed to receive result: {:?}", e))?
+}
+
+/// Non-blocking variant of [`send_download_buffer`]: identical behavior, but
+/// waits on the webview thread's result with a `tokio::sync::oneshot`
+/// receiver `.await`ed instead of a blocking `mpsc::Receiver::recv()`, so
+/// the async command worker driving this future is free to run other work
+/// while `with_webview`'s dispatch is in flight. Matters when many blobs are
+/// posted concurrently — the blocking `recv` in `send_download_buffer`
+/// serializes them onto whatever pool of worker threads the runtime has.
+#[command]
+pub async fn send_download_buffer_async<R: Runtime>(
+    window: WebviewWindow<R>,
+    name: String,
+) -> Result<DownloadBufferResponse, String> {
+    use webview2_com::Microsoft::Web::WebView2::Win32::{
+        ICoreWebView2Environment12, ICoreWebView2_17, ICoreWebView2_2,
+        COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_ONLY,
+    };
+    use windows::core::PCWSTR;
+    use windows_core::Interface;
+
+    let (version, data_len) = memio_platform::windows::read_shared_info(&name)?;
+
+    tracing::info!(
+        "[MemioWindows] send_download_buffer_async: '{}' v{} ({} bytes)",
+        name,
+        version,
+        data_len
+    );
+
+    if crate::windows_blob_server::is_enabled() {
+        let (_version, data) = memio_platform::windows::read_from_shared(&name)?;
+        let url = crate::windows_blob_server::register_blob(&name, data)?;
+        return Ok(DownloadBufferResponse {
+            name,
+            version,
+            size: data_len,
+            posted: true,
+            url: Some(url),
+        });
+    }
+
+    let (tx, rx) = tokio::sync::oneshot::channel::<Result<DownloadBufferResponse, String>>();
+    let name_clone = name.clone();
+
+    let webview_result = window.with_webview(move |webview| {
+        let result: Result<DownloadBufferResponse, String> = (|| {
+            let controller = webview.controller();
+            unsafe {
+                let core_webview = controller.CoreWebView2()
+                    .map_err(|e| format!("Failed to get CoreWebView2: {:?}", e))?;
+
+                let webview2: ICoreWebView2_2 = core_webview.cast()
+                    .map_err(|e| format!("Failed to cast to WebView2_2: {:?}", e))?;
+
+                let env = webview2.Environment()
+                    .map_err(|e| format!("Failed to get Environment: {:?}", e))?;
+
+                let env12: ICoreWebView2Environment12 = env.cast()
+                    .map_err(|e| format!("Failed to cast to Environment12: {:?}", e))?;
+
+                let buffer_name = format!("download_{}", name_clone);
+
+                let (ptr, _actual_size) = crate::windows_shared_buffer::create_shared_buffer(
+                    &env12, &buffer_name, data_len as u64
+                ).map_err(|e| format!("Failed to create SharedBuffer: {:?}", e))?;
+
+                memio_platform::windows::copy_shared_to_ptr(&name_clone, ptr, data_len)
+                    .map_err(|e| format!("Failed to copy to SharedBuffer: {:?}", e))?;
+
+                let webview17: ICoreWebView2_17 = core_webview.cast()
+                    .map_err(|e| format!("Failed to cast to WebView2_17: {:?}", e))?;
+
+                let metadata = format!(
+                    r#"{{"name":"{}","bufferName":"{}","version":{},"size":{},"forDownload":true}}"#,
+                    name_clone, buffer_name, version, data_len
+                );
+                let wide: Vec<u16> = metadata.encode_utf16().chain(std::iter::once(0)).collect();
+
+                {
+                    let registry = crate::windows_shared_buffer::get_registry_internal();
+                    let reg = registry.lock().unwrap();
+                    if let Some(entry) = reg.get(&buffer_name) {
+                        webview17.PostSharedBufferToScript(
+                            &entry.buffer,
+                            COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_ONLY,
+                            PCWSTR::from_raw(wide.as_ptr()),
+                        ).map_err(|e| format!("Failed to post SharedBuffer: {:?}", e))?;
+                    } else {
+                        return Err(format!("Buffer '{}' not found in registry", buffer_name));
+                    }
+                }
+
+                tracing::info!(
+                    "[MemioWindows] Download buffer posted: '{}' ({} bytes)",
+                    buffer_name, data_len
+                );
+
+                Ok(DownloadBufferResponse {
+                    name: name_clone.clone(),
+                    version,
+                    size: data_len,
+                    posted: true,
+                    url: None,
                 })
             }
         })();
@@ -650,6 +1504,369 @@ pub async fn send_download_buffer<R: Runtime>(
         return Err(format!("with_webview failed: {:?}", e));
     }
 
+    rx.await
+        .map_err(|e| format!("Failed to receive result: {:?}", e))?
+}
+
+// ============================================================================
+// Chunked Streaming (pull-based)
+// ============================================================================
+
+/// Where a pull-based stream's bytes come from. `Region` never materializes
+/// the whole payload — each `read_chunk` call reads only the requested
+/// window straight out of the memio region — so a multi-gigabyte region can
+/// be paged through without inflating memory. `Materialized` holds bytes
+/// already resident (e.g. `Vec<u8>` assembled some other way) for callers
+/// that have no region to read from.
+enum StreamSource {
+    Region { name: String },
+    Materialized(Vec<u8>),
+}
+
+struct StreamState {
+    source: StreamSource,
+    size: usize,
+    /// Furthest offset any `read_chunk` call has read up to, so `stats`-style
+    /// callers can tell a stalled consumer from one that's caught up.
+    cursor: usize,
+}
+
+static STREAM_SESSIONS: OnceLock<Mutex<HashMap<String, StreamState>>> = OnceLock::new();
+
+fn stream_sessions() -> &'static Mutex<HashMap<String, StreamState>> {
+    STREAM_SESSIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamBlobResponse {
+    pub stream_id: String,
+    pub size: usize,
+    pub version: u64,
+}
+
+/// Registers a pull-based stream over the memio region named `name` and
+/// returns an opaque `stream_id` the frontend pulls fixed-size chunks from
+/// via [`read_chunk`] at its own pace, instead of having chunks pushed to it
+/// faster than it can keep up with (the failure mode event-based delivery
+/// hits on hundreds-of-thousands-of-row payloads).
+#[command]
+pub async fn stream_blob(name: String) -> Result<StreamBlobResponse, String> {
+    let (version, size) = memio_platform::windows::read_shared_info(&name)?;
+    let stream_id = format!("{}__stream_{}", name, version);
+
+    stream_sessions().lock().unwrap().insert(
+        stream_id.clone(),
+        StreamState {
+            source: StreamSource::Region { name },
+            size,
+            cursor: 0,
+        },
+    );
+
+    Ok(StreamBlobResponse {
+        stream_id,
+        size,
+        version,
+    })
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadChunkResponse {
+    pub data: Vec<u8>,
+    /// `true` once this chunk reaches the end of the stream, so the
+    /// frontend knows to stop pulling instead of polling an exhausted
+    /// stream for an empty chunk.
+    pub exhausted: bool,
+}
+
+/// Pulls `len` bytes starting at `offset` from the stream `id` was returned
+/// for by [`stream_blob`]. Callers drive their own pace by choosing when to
+/// call this, which is the backpressure mechanism: nothing is pushed to the
+/// frontend until it asks.
+#[command]
+pub async fn read_chunk(id: String, offset: usize, len: usize) -> Result<ReadChunkResponse, String> {
+    let mut sessions = stream_sessions().lock().unwrap();
+    let state = sessions
+        .get_mut(&id)
+        .ok_or_else(|| format!("Stream '{}' not found", id))?;
+
+    let clamped_len = len.min(state.size.saturating_sub(offset));
+    let data = match &state.source {
+        StreamSource::Region { name } => {
+            memio_platform::windows::read_shared_range(name, offset, clamped_len)?
+        }
+        StreamSource::Materialized(bytes) => {
+            bytes[offset..offset + clamped_len].to_vec()
+        }
+    };
+
+    state.cursor = state.cursor.max(offset + clamped_len);
+    let exhausted = state.cursor >= state.size;
+    if exhausted {
+        sessions.remove(&id);
+    }
+
+    Ok(ReadChunkResponse { data, exhausted })
+}
+
+// ============================================================================
+// SharedBuffer host-object parity
+// ============================================================================
+
+/// Creates a WebView2 `ICoreWebView2SharedBuffer` named `name`, reaching
+/// `ICoreWebView2Environment12::CreateSharedBuffer` the same way
+/// `send_download_buffer` does. Requires `ICoreWebView2_2`/`Environment12`;
+/// on WebView2 runtimes that predate them this fails, and callers should
+/// fall back to `windows_hostobject::memio_register_host_object` instead.
+#[command]
+pub async fn memio_create_shared_buffer<R: Runtime>(
+    window: WebviewWindow<R>,
+    name: String,
+    size: u64,
+) -> Result<(), String> {
+    policy::check(window.label(), &name, MemioAction::Create).map_err(|e| e.to_string())?;
+
+    use std::sync::mpsc;
+    use webview2_com::Microsoft::Web::WebView2::Win32::{
+        ICoreWebView2Environment12, ICoreWebView2_2,
+    };
+    use windows_core::Interface;
+
+    let (tx, rx) = mpsc::channel::<Result<(), String>>();
+    let name_clone = name.clone();
+
+    let webview_result = window.with_webview(move |webview| {
+        let result: Result<(), String> = (|| {
+            let controller = webview.controller();
+            unsafe {
+                let core_webview = controller
+                    .CoreWebView2()
+                    .map_err(|e| format!("Failed to get CoreWebView2: {:?}", e))?;
+
+                let webview2: ICoreWebView2_2 = core_webview
+                    .cast()
+                    .map_err(|e| format!("Failed to cast to WebView2_2: {:?}", e))?;
+
+                let env = webview2
+                    .Environment()
+                    .map_err(|e| format!("Failed to get Environment: {:?}", e))?;
+
+                let env12: ICoreWebView2Environment12 = env.cast().map_err(|e| {
+                    format!("SharedBuffer unsupported (no Environment12): {:?}", e)
+                })?;
+
+                crate::windows_shared_buffer::create_shared_buffer(&env12, &name_clone, size)
+                    .map_err(|e| format!("Failed to create SharedBuffer: {:?}", e))?;
+            }
+            Ok(())
+        })();
+
+        let _ = tx.send(result);
+    });
+
+    if let Err(e) = webview_result {
+        return Err(format!("with_webview failed: {:?}", e));
+    }
+
     rx.recv()
         .map_err(|e| format!("Failed to receive result: {:?}", e))?
 }
+
+/// Posts a SharedBuffer already created with `memio_create_shared_buffer` to
+/// script, delivered via the `sharedbufferreceived` event.
+#[command]
+pub async fn memio_post_buffer<R: Runtime>(
+    window: WebviewWindow<R>,
+    name: String,
+) -> Result<(), String> {
+    use std::sync::mpsc;
+
+    let (tx, rx) = mpsc::channel::<Result<(), String>>();
+    let name_clone = name.clone();
+
+    let webview_result = window.with_webview(move |webview| {
+        let result: Result<(), String> = (|| {
+            let controller = webview.controller();
+            unsafe {
+                let core_webview = controller
+                    .CoreWebView2()
+                    .map_err(|e| format!("Failed to get CoreWebView2: {:?}", e))?;
+
+                crate::windows_shared_buffer::post_buffer_to_script(&core_webview, &name_clone, None)
+                    .map_err(|e| format!("Failed to post SharedBuffer: {:?}", e))?;
+            }
+            Ok(())
+        })();
+
+        let _ = tx.send(result);
+    });
+
+    if let Err(e) = webview_result {
+        return Err(format!("with_webview failed: {:?}", e));
+    }
+
+    rx.recv()
+        .map_err(|e| format!("Failed to receive result: {:?}", e))?
+}
+
+// ============================================================================
+// Streaming Ring Buffer
+// ============================================================================
+
+/// Data capacity (excluding the ring header) of every streaming SharedBuffer.
+/// Must be a power of two — see `windows_shared_ring::WindowsSharedRingBuffer`.
+const STREAM_DEFAULT_CAPACITY: usize = 64 * 1024;
+
+static STREAM_RINGS: OnceLock<Mutex<HashMap<String, crate::windows_shared_ring::WindowsSharedRingBuffer>>> =
+    OnceLock::new();
+
+fn stream_rings() -> &'static Mutex<HashMap<String, crate::windows_shared_ring::WindowsSharedRingBuffer>> {
+    STREAM_RINGS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Lightweight "data available" event emitted after every `memio_stream_push`
+/// once the stream's SharedBuffer has already been posted to script.
+#[derive(serde::Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamDataEvent {
+    pub name: String,
+    pub head: u64,
+}
+
+/// Appends `data` to the streaming ring buffer named `name`.
+///
+/// On the first call for a given `name`, this creates the backing
+/// SharedBuffer, lays a `WindowsSharedRingBuffer` over it, and posts it to
+/// script once (`forStream: true`). Every later call only pushes into the
+/// existing ring and emits `memio://stream-data` with the new `head` cursor
+/// — the buffer itself is never re-posted.
+#[command]
+pub async fn memio_stream_push<R: Runtime>(
+    window: WebviewWindow<R>,
+    name: String,
+    data: Vec<u8>,
+) -> Result<u64, String> {
+    policy::check(window.label(), &name, MemioAction::Write).map_err(|e| e.to_string())?;
+
+    if data.len() > STREAM_DEFAULT_CAPACITY {
+        return Err(format!(
+            "push of {} bytes exceeds stream capacity {}",
+            data.len(),
+            STREAM_DEFAULT_CAPACITY
+        ));
+    }
+
+    let already_created = stream_rings().lock().unwrap().contains_key(&name);
+
+    if !already_created {
+        policy::check(window.label(), &name, MemioAction::Create).map_err(|e| e.to_string())?;
+
+        use std::sync::mpsc;
+        use webview2_com::Microsoft::Web::WebView2::Win32::{
+            ICoreWebView2Environment12, ICoreWebView2_17, ICoreWebView2_2,
+            COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_WRITE,
+        };
+        use windows::core::PCWSTR;
+        use windows_core::Interface;
+
+        let region_size =
+            (crate::windows_shared_ring::RING_HEADER_SIZE + STREAM_DEFAULT_CAPACITY) as u64;
+        let (tx, rx) = mpsc::channel::<Result<(), String>>();
+        let name_clone = name.clone();
+
+        let webview_result = window.with_webview(move |webview| {
+            let result: Result<(), String> = (|| {
+                let controller = webview.controller();
+                unsafe {
+                    let core_webview = controller
+                        .CoreWebView2()
+                        .map_err(|e| format!("Failed to get CoreWebView2: {:?}", e))?;
+
+                    let webview2: ICoreWebView2_2 = core_webview
+                        .cast()
+                        .map_err(|e| format!("Failed to cast to WebView2_2: {:?}", e))?;
+
+                    let env = webview2
+                        .Environment()
+                        .map_err(|e| format!("Failed to get Environment: {:?}", e))?;
+
+                    let env12: ICoreWebView2Environment12 = env
+                        .cast()
+                        .map_err(|e| format!("Failed to cast to Environment12: {:?}", e))?;
+
+                    let (ptr, actual_size) = crate::windows_shared_buffer::create_shared_buffer(
+                        &env12,
+                        &name_clone,
+                        region_size,
+                    )
+                    .map_err(|e| format!("Failed to create SharedBuffer: {:?}", e))?;
+
+                    let ring = crate::windows_shared_ring::WindowsSharedRingBuffer::init(
+                        ptr,
+                        actual_size,
+                        STREAM_DEFAULT_CAPACITY,
+                    )?;
+                    stream_rings().lock().unwrap().insert(name_clone.clone(), ring);
+
+                    let webview17: ICoreWebView2_17 = core_webview
+                        .cast()
+                        .map_err(|e| format!("Failed to cast to WebView2_17: {:?}", e))?;
+
+                    let metadata = format!(
+                        r#"{{"name":"{}","size":{},"forStream":true,"headerSize":{},"capacity":{}}}"#,
+                        name_clone,
+                        actual_size,
+                        crate::windows_shared_ring::RING_HEADER_SIZE,
+                        STREAM_DEFAULT_CAPACITY
+                    );
+                    let wide: Vec<u16> =
+                        metadata.encode_utf16().chain(std::iter::once(0)).collect();
+
+                    let registry = crate::windows_shared_buffer::get_registry_internal();
+                    let reg = registry.lock().unwrap();
+                    let entry = reg
+                        .get(&name_clone)
+                        .ok_or_else(|| format!("Buffer '{}' not found in registry", name_clone))?;
+                    webview17
+                        .PostSharedBufferToScript(
+                            &entry.buffer,
+                            COREWEBVIEW2_SHARED_BUFFER_ACCESS_READ_WRITE,
+                            PCWSTR::from_raw(wide.as_ptr()),
+                        )
+                        .map_err(|e| format!("Failed to post SharedBuffer: {:?}", e))?;
+                }
+                Ok(())
+            })();
+
+            let _ = tx.send(result);
+        });
+
+        if let Err(e) = webview_result {
+            return Err(format!("with_webview failed: {:?}", e));
+        }
+
+        rx.recv()
+            .map_err(|e| format!("Failed to receive result: {:?}", e))??;
+    }
+
+    let head = {
+        let rings = stream_rings().lock().unwrap();
+        let ring = rings
+            .get(&name)
+            .ok_or_else(|| format!("Stream '{}' not found", name))?;
+        ring.push(&data)?
+    };
+
+    use tauri::Emitter;
+    let _ = window.emit(
+        "memio://stream-data",
+        StreamDataEvent {
+            name: name.clone(),
+            head,
+        },
+    );
+
+    Ok(head)
+}
@@ -0,0 +1,313 @@
+//! Opt-in localhost HTTP server for serving memio blobs directly to the
+//! webview, as an alternative to the default injection path where
+//! [`send_download_buffer`](crate::windows::send_download_buffer) copies
+//! bytes into a WebView2 SharedBuffer and posts it via `with_webview`.
+//!
+//! Mirrors `tauri-plugin-localhost`: a host app installs a
+//! [`MemioBlobServerBuilder`] once during setup, and from then on every
+//! `send_download_buffer` call also registers its bytes here, with the
+//! response's `url` field pointing at `http://127.0.0.1:<port>/<name>` so
+//! the frontend can `fetch`/`<img src>` it directly instead of receiving an
+//! injected payload. Off by default — the SharedBuffer path stays the
+//! default since it never opens a network socket.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+use tauri::command;
+
+struct BlobServerConfig {
+    port: u16,
+    max_bytes: Option<usize>,
+    max_entries: Option<usize>,
+}
+
+struct BlobEntry {
+    data: Vec<u8>,
+    version: u64,
+    last_used: Instant,
+}
+
+static BLOB_SERVER_CONFIG: OnceLock<BlobServerConfig> = OnceLock::new();
+static BLOB_STORE: OnceLock<Mutex<HashMap<String, BlobEntry>>> = OnceLock::new();
+static SERVER_PORT: OnceLock<u16> = OnceLock::new();
+
+fn blob_store() -> &'static Mutex<HashMap<String, BlobEntry>> {
+    BLOB_STORE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Builds the opt-in localhost blob server configuration. Call
+/// [`install`](Self::install) once during plugin setup, before any
+/// `send_download_buffer` call, to switch that command over to also serving
+/// each posted blob over HTTP.
+#[derive(Default)]
+pub struct MemioBlobServerBuilder {
+    port: Option<u16>,
+    max_bytes: Option<usize>,
+    max_entries: Option<usize>,
+}
+
+impl MemioBlobServerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds the server to a fixed port instead of letting the OS pick an
+    /// available one.
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Caps the blob store's total bytes across all registered blobs.
+    /// Registering a blob that would push the total over this bound evicts
+    /// least-recently-accessed entries (oldest `last_used`, not oldest
+    /// `version`) until it fits, including the new blob itself if it's
+    /// larger than the whole cap.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Caps the number of distinct blob names the store retains, evicting
+    /// the least-recently-accessed entry whenever registering a new name
+    /// would exceed it.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Installs this configuration as the process-wide blob server config.
+    /// Only the first call across the process takes effect; the server
+    /// itself doesn't start listening until the first blob is registered.
+    pub fn install(self) {
+        let _ = BLOB_SERVER_CONFIG.set(BlobServerConfig {
+            port: self.port.unwrap_or(0),
+            max_bytes: self.max_bytes,
+            max_entries: self.max_entries,
+        });
+    }
+}
+
+/// Returns `true` if a host app has installed a [`MemioBlobServerBuilder`].
+pub(crate) fn is_enabled() -> bool {
+    BLOB_SERVER_CONFIG.get().is_some()
+}
+
+/// Registers `data` under `name` in the blob store, starting the HTTP
+/// server on first use, and returns the URL the frontend can fetch it from.
+/// Bumps `name`'s version if it was already registered, and evicts
+/// least-recently-accessed entries first if doing so would exceed
+/// `max_bytes`/`max_entries`.
+pub(crate) fn register_blob(name: &str, data: Vec<u8>) -> Result<String, String> {
+    let port = ensure_started()?;
+
+    let mut store = blob_store().lock().unwrap();
+    let version = store.get(name).map(|e| e.version + 1).unwrap_or(1);
+    store.insert(
+        name.to_string(),
+        BlobEntry {
+            data,
+            version,
+            last_used: Instant::now(),
+        },
+    );
+    evict_over_limits(&mut store);
+
+    Ok(format!("http://127.0.0.1:{}/{}", port, name))
+}
+
+fn evict_over_limits(store: &mut HashMap<String, BlobEntry>) {
+    let config = BLOB_SERVER_CONFIG.get();
+    let max_bytes = config.and_then(|c| c.max_bytes);
+    let max_entries = config.and_then(|c| c.max_entries);
+
+    loop {
+        let total_bytes: usize = store.values().map(|e| e.data.len()).sum();
+        let over_bytes = max_bytes.is_some_and(|limit| total_bytes > limit);
+        let over_entries = max_entries.is_some_and(|limit| store.len() > limit);
+
+        if !over_bytes && !over_entries {
+            break;
+        }
+
+        let Some(lru_name) = store
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(name, _)| name.clone())
+        else {
+            break;
+        };
+        store.remove(&lru_name);
+    }
+}
+
+/// Current memory usage of the blob store.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlobServerStats {
+    pub bytes_used: usize,
+    pub entry_count: usize,
+}
+
+/// Reports the blob store's current size, so a long-running app can check
+/// it isn't quietly accumulating transient blobs forever.
+#[command]
+pub async fn memio_blob_server_stats() -> Result<BlobServerStats, String> {
+    let store = blob_store().lock().unwrap();
+    Ok(BlobServerStats {
+        bytes_used: store.values().map(|e| e.data.len()).sum(),
+        entry_count: store.len(),
+    })
+}
+
+/// Removes a single blob from the store, freeing its bytes immediately
+/// rather than waiting for LRU eviction to reclaim them.
+#[command]
+pub async fn memio_remove_blob(name: String) -> Result<(), String> {
+    blob_store().lock().unwrap().remove(&name);
+    Ok(())
+}
+
+/// Removes every blob from the store.
+#[command]
+pub async fn memio_clear_blobs() -> Result<(), String> {
+    blob_store().lock().unwrap().clear();
+    Ok(())
+}
+
+fn ensure_started() -> Result<u16, String> {
+    if let Some(&port) = SERVER_PORT.get() {
+        return Ok(port);
+    }
+
+    let requested_port = BLOB_SERVER_CONFIG.get().map(|c| c.port).unwrap_or(0);
+    let server = tiny_http::Server::http(("127.0.0.1", requested_port))
+        .map_err(|e| format!("Failed to start memio blob server: {e}"))?;
+    let bound_port = server
+        .server_addr()
+        .to_ip()
+        .map(|addr| addr.port())
+        .unwrap_or(requested_port);
+
+    std::thread::spawn(move || {
+        for request in server.incoming_requests() {
+            handle_request(request);
+        }
+    });
+
+    let _ = SERVER_PORT.set(bound_port);
+    tracing::info!(
+        "[MemioWindows] Blob server listening on http://127.0.0.1:{}",
+        bound_port
+    );
+    Ok(bound_port)
+}
+
+fn handle_request(request: tiny_http::Request) {
+    let name = request.url().trim_start_matches('/').to_string();
+    let data = {
+        let mut store = blob_store().lock().unwrap();
+        store.get_mut(&name).map(|entry| {
+            entry.last_used = Instant::now();
+            entry.data.clone()
+        })
+    };
+    let Some(bytes) = data else {
+        let _ = request.respond(tiny_http::Response::empty(404));
+        return;
+    };
+
+    let content_type = sniff_content_type(&name, &bytes);
+    let range = request
+        .headers()
+        .iter()
+        .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case("Range"))
+        .and_then(|h| parse_range(h.value.as_str(), bytes.len()));
+
+    let content_type_header =
+        tiny_http::Header::from_bytes(&b"Content-Type"[..], content_type.as_bytes()).unwrap();
+
+    match range {
+        Some((start, end)) => {
+            let slice = bytes[start..=end].to_vec();
+            let content_range_header = tiny_http::Header::from_bytes(
+                &b"Content-Range"[..],
+                format!("bytes {}-{}/{}", start, end, bytes.len()).as_bytes(),
+            )
+            .unwrap();
+            let response = tiny_http::Response::from_data(slice)
+                .with_status_code(206)
+                .with_header(content_type_header)
+                .with_header(content_range_header);
+            let _ = request.respond(response);
+        }
+        None => {
+            let response = tiny_http::Response::from_data(bytes).with_header(content_type_header);
+            let _ = request.respond(response);
+        }
+    }
+}
+
+/// Infers a blob's `Content-Type` from a magic-byte sniff of its first few
+/// bytes, falling back to `mime_guess` on `name`'s extension, and finally to
+/// `application/octet-stream` — never `text/html`, so a binary resource
+/// served through this path can't be misinterpreted as markup.
+fn sniff_content_type(name: &str, data: &[u8]) -> String {
+    if let Some(mime) = sniff_magic_bytes(data) {
+        return mime.to_string();
+    }
+    mime_guess::from_path(name)
+        .first()
+        .map(|m| m.essence_str().to_string())
+        .unwrap_or_else(|| "application/octet-stream".to_string())
+}
+
+fn sniff_magic_bytes(data: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+    ];
+    SIGNATURES
+        .iter()
+        .find(|(sig, _)| data.starts_with(sig))
+        .map(|(_, mime)| *mime)
+}
+
+/// Parses a `Range: bytes=start-end` header against a blob of `total` bytes,
+/// returning an inclusive `(start, end)` byte range clamped to the blob's
+/// bounds, or `None` for anything malformed or unsatisfiable (the caller
+/// then serves the whole blob instead of erroring).
+fn parse_range(header: &str, total: usize) -> Option<(usize, usize)> {
+    let spec = header.strip_prefix("bytes=")?;
+    let (start_str, end_str) = spec.split_once('-')?;
+
+    if total == 0 {
+        return None;
+    }
+
+    let start: usize = if start_str.is_empty() {
+        let suffix_len: usize = end_str.parse().ok()?;
+        total.saturating_sub(suffix_len)
+    } else {
+        start_str.parse().ok()?
+    };
+
+    let end: usize = if start_str.is_empty() {
+        total - 1
+    } else if end_str.is_empty() {
+        total - 1
+    } else {
+        end_str.parse::<usize>().ok()?.min(total - 1)
+    };
+
+    if start > end || start >= total {
+        return None;
+    }
+
+    Some((start, end))
+}
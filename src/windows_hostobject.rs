@@ -7,28 +7,33 @@
 //! - More compatible (works on older WebView2 versions)
 //! - Simpler than SharedBuffer
 
+use crate::policy::{self, MemioAction};
 use tauri::{command, Runtime, WebviewWindow};
 use windows::core::{implement, IInspectable, HSTRING};
-use windows::Win32::System::WinRT::IInspectable as WinRTInspectable;
 
 /// Tauri command to register the MemioHostObject.
 ///
 /// This must be called once during app initialization to expose
 /// the `window.chrome.webview.hostObjects.memioShared` object to JavaScript.
+/// This is the more compatible transport: it works on WebView2 runtimes that
+/// predate `ICoreWebView2SharedBuffer`, so apps that can't rely on
+/// `memio_create_shared_buffer`/`memio_post_buffer` should register this
+/// instead.
 #[command]
-pub async fn register_memio_host_object<R: Runtime>(
+pub async fn memio_register_host_object<R: Runtime>(
     window: WebviewWindow<R>,
 ) -> Result<(), String> {
+    let window_label = window.label().to_string();
     window
-        .with_webview(|webview| {
+        .with_webview(move |webview| {
             #[cfg(target_os = "windows")]
             unsafe {
-                register_host_object_impl(webview)
+                register_host_object_impl(webview, window_label)
             }
-            
+
             #[cfg(not(target_os = "windows"))]
             {
-                let _ = webview;
+                let _ = (webview, window_label);
                 Err("Host Object only available on Windows".to_string())
             }
         })
@@ -38,32 +43,22 @@ pub async fn register_memio_host_object<R: Runtime>(
 }
 
 #[cfg(target_os = "windows")]
-unsafe fn register_host_object_impl(webview: &tauri::Webview) -> Result<(), String> {
-    use windows::core::Interface;
-    use windows::Web::WebView2::Core::ICoreWebView2;
-
-    // Get CoreWebView2 from Tauri webview
-    let webview_ptr = webview.as_ptr() as *mut std::ffi::c_void;
-    let core: ICoreWebView2 = {
-        // Tauri's webview is actually a WRY webview
-        // On Windows, WRY wraps WebView2's ICoreWebView2
-        // We need to extract it carefully
-        
-        // This is a simplification - actual implementation may vary
-        // based on Tauri version. The real code would need to:
-        // 1. Cast to WRY's WebView struct
-        // 2. Access its controller field
-        // 3. Call CoreWebView2() on it
-        
-        // For now, return error with instructions
-        return Err(
-            "CoreWebView2 access not yet implemented. See windows_hostobject.rs for details"
-                .to_string(),
-        );
-    };
-
-    // Create our host object
-    let host_object = MemioHostObject::new();
+unsafe fn register_host_object_impl(webview: &tauri::Webview, window_label: String) -> Result<(), String> {
+    use webview2_com::Microsoft::Web::WebView2::Win32::ICoreWebView2;
+    use windows_core::Interface;
+
+    // WRY exposes the underlying WebView2 controller directly; go through
+    // it rather than the raw platform handle to reach ICoreWebView2, the
+    // same route `windows::with_webview` callers use elsewhere in this crate.
+    let controller = webview.controller();
+    let core: ICoreWebView2 = controller
+        .CoreWebView2()
+        .map_err(|e| format!("Failed to get CoreWebView2: {:?}", e))?;
+
+    // Create our host object, carrying the window label so its
+    // ReadSharedState/WriteSharedState methods can enforce the ACL policy
+    // for the window they were registered on.
+    let host_object = MemioHostObject::new(window_label);
     let inspectable: IInspectable = host_object.cast().map_err(|e| e.to_string())?;
 
     // Register it with name "memioShared"
@@ -83,11 +78,15 @@ unsafe fn register_host_object_impl(webview: &tauri::Webview) -> Result<(), Stri
 /// - `writeSharedState(name: string, data: string, version: number): Promise<boolean>`
 /// - `getVersion(name: string): Promise<number>`
 #[implement(IInspectable)]
-struct MemioHostObject {}
+struct MemioHostObject {
+    window_label: String,
+}
 
 impl MemioHostObject {
-    fn new() -> Self {
-        Self {}
+    fn new(window_label: impl Into<String>) -> Self {
+        Self {
+            window_label: window_label.into(),
+        }
     }
 
     /// Reads data from memio region and returns as Base64 JSON.
@@ -104,6 +103,9 @@ impl MemioHostObject {
 
         tracing::debug!("ReadSharedState called: name={}, lastVersion={}", name_str, last_version);
 
+        policy::check(&self.window_label, &name_str, MemioAction::Read)
+            .map_err(to_com_error)?;
+
         // Read from memio-platform
         let (version, data) = match memio_platform::windows::read_from_shared(&name_str) {
             Ok(result) => result,
@@ -154,6 +156,9 @@ impl MemioHostObject {
 
         tracing::debug!("WriteSharedState called: name={}, version={}", name_str, version);
 
+        policy::check(&self.window_label, &name_str, MemioAction::Write)
+            .map_err(to_com_error)?;
+
         // Decode Base64
         use base64::Engine;
         let data = match base64::engine::general_purpose::STANDARD.decode(data_b64_str) {
@@ -194,11 +199,17 @@ impl MemioHostObject {
     }
 }
 
+/// Converts a policy denial into the `windows::core::Error` shape the COM
+/// methods on `MemioHostObject` must return.
+fn to_com_error(err: memio_core::MemioError) -> windows::core::Error {
+    windows::core::Error::new::<&str>(windows::core::HRESULT(-1), &err.to_string())
+}
+
 /// Helper commands for testing
 
 #[command]
 pub async fn test_host_object_read(name: String) -> Result<String, String> {
-    let host_object = MemioHostObject::new();
+    let host_object = MemioHostObject::new("test");
     let result = host_object
         .ReadSharedState(HSTRING::from(&name), -1)
         .map_err(|e| e.to_string())?;
@@ -214,7 +225,7 @@ pub async fn test_host_object_write(
     use base64::Engine;
     let data_b64 = base64::engine::general_purpose::STANDARD.encode(&data);
 
-    let host_object = MemioHostObject::new();
+    let host_object = MemioHostObject::new("test");
     let result = host_object
         .WriteSharedState(
             HSTRING::from(&name),
@@ -231,7 +242,7 @@ mod tests {
 
     #[test]
     fn test_host_object_creation() {
-        let _host_object = MemioHostObject::new();
+        let _host_object = MemioHostObject::new("test");
         // Just verify it compiles and constructs
     }
 
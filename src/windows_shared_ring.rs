@@ -0,0 +1,157 @@
+//! Single-producer/single-consumer ring buffer over a WebView2 SharedBuffer.
+//!
+//! `windows_shared_buffer` treats a SharedBuffer as a flat, bounds-checked
+//! byte range, so a streaming producer (continuously appended frames/log
+//! lines) has to re-post the whole buffer to script on every append.
+//! `WindowsSharedRingBuffer` instead lays a ring over one
+//! `ICoreWebView2SharedBuffer`: the buffer is posted once, and each
+//! subsequent `push` only needs a lightweight "data available" event
+//! carrying the new `head` for the JS consumer to read up to.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Cursor header written at the front of the SharedBuffer region, ahead of
+/// the ring's data area — the same "small fixed header in front of the
+/// payload" convention `shared_header` uses for whole-buffer memio regions.
+#[repr(C)]
+struct RingHeader {
+    capacity: AtomicU64,
+    head: AtomicU64,
+    tail: AtomicU64,
+}
+
+/// Byte size of [`RingHeader`]; the data area starts immediately after it.
+pub const RING_HEADER_SIZE: usize = std::mem::size_of::<RingHeader>();
+
+/// A single-producer/single-consumer ring over a WebView2 SharedBuffer.
+///
+/// `ptr` must point at a region at least `RING_HEADER_SIZE + capacity`
+/// bytes long (as returned by `windows_shared_buffer::create_shared_buffer`),
+/// and `capacity` (the data area, excluding the header) must be a power of
+/// two so `push`/`pop` can mask offsets instead of taking a modulo.
+pub struct WindowsSharedRingBuffer {
+    ptr: *mut u8,
+    capacity: usize,
+    mask: usize,
+}
+
+// Safety: all access goes through the header's atomics; the data area is
+// only touched after the corresponding cursor has been validated.
+unsafe impl Send for WindowsSharedRingBuffer {}
+unsafe impl Sync for WindowsSharedRingBuffer {}
+
+impl WindowsSharedRingBuffer {
+    /// Writes a fresh ring header at the front of `ptr` and returns a ring
+    /// over it. `region_size` must equal `RING_HEADER_SIZE + capacity`.
+    pub fn init(ptr: *mut u8, region_size: u64, capacity: usize) -> Result<Self, String> {
+        if !capacity.is_power_of_two() {
+            return Err(format!("ring capacity {} must be a power of two", capacity));
+        }
+        if region_size as usize != RING_HEADER_SIZE + capacity {
+            return Err(format!(
+                "region size {} does not match header ({}) + capacity ({})",
+                region_size, RING_HEADER_SIZE, capacity
+            ));
+        }
+
+        unsafe {
+            (ptr as *mut RingHeader).write(RingHeader {
+                capacity: AtomicU64::new(capacity as u64),
+                head: AtomicU64::new(0),
+                tail: AtomicU64::new(0),
+            });
+        }
+
+        Ok(Self {
+            ptr,
+            capacity,
+            mask: capacity - 1,
+        })
+    }
+
+    fn header(&self) -> &RingHeader {
+        unsafe { &*(self.ptr as *const RingHeader) }
+    }
+
+    fn data_ptr(&self) -> *mut u8 {
+        unsafe { self.ptr.add(RING_HEADER_SIZE) }
+    }
+
+    /// Bytes currently queued and not yet popped.
+    pub fn available(&self) -> usize {
+        let header = self.header();
+        let head = header.head.load(Ordering::Acquire);
+        let tail = header.tail.load(Ordering::Acquire);
+        head.wrapping_sub(tail) as usize
+    }
+
+    /// Appends `data`, returning the new `head` cursor. Errs without
+    /// writing anything if `data` doesn't fit in the free space.
+    ///
+    /// The payload is copied before the `head` cursor is published with
+    /// release ordering, so a JS consumer polling `head` with acquire
+    /// semantics on the same `ArrayBuffer` never observes a cursor advance
+    /// before the bytes it covers.
+    pub fn push(&self, data: &[u8]) -> Result<u64, String> {
+        let header = self.header();
+        let head = header.head.load(Ordering::Acquire);
+        let tail = header.tail.load(Ordering::Acquire);
+        let used = head.wrapping_sub(tail) as usize;
+        let free = self.capacity - used;
+
+        if data.len() > free {
+            return Err(format!(
+                "ring full: {} bytes requested, {} free of {}",
+                data.len(),
+                free,
+                self.capacity
+            ));
+        }
+
+        let write_pos = (head as usize) & self.mask;
+        let first = data.len().min(self.capacity - write_pos);
+        let second = data.len() - first;
+        let base = self.data_ptr();
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), base.add(write_pos), first);
+            if second > 0 {
+                std::ptr::copy_nonoverlapping(data.as_ptr().add(first), base, second);
+            }
+        }
+
+        let new_head = head.wrapping_add(data.len() as u64);
+        header.head.store(new_head, Ordering::Release);
+        Ok(new_head)
+    }
+
+    /// Pops up to `out.len()` bytes, returning the number popped.
+    pub fn pop(&self, out: &mut [u8]) -> usize {
+        let header = self.header();
+        let head = header.head.load(Ordering::Acquire);
+        let tail = header.tail.load(Ordering::Acquire);
+        let available = head.wrapping_sub(tail) as usize;
+
+        let to_read = out.len().min(available);
+        if to_read == 0 {
+            return 0;
+        }
+
+        let read_pos = (tail as usize) & self.mask;
+        let first = to_read.min(self.capacity - read_pos);
+        let second = to_read - first;
+        let base = self.data_ptr();
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(base.add(read_pos), out.as_mut_ptr(), first);
+            if second > 0 {
+                std::ptr::copy_nonoverlapping(base, out.as_mut_ptr().add(first), second);
+            }
+        }
+
+        header
+            .tail
+            .store(tail.wrapping_add(to_read as u64), Ordering::Release);
+        to_read
+    }
+}